@@ -40,6 +40,9 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
         padding: 2.0,
         inner_margin: 2.0,
         inner_padding: 4.0,
+        label_value_gap: Val::Px(8.0),
+        border: UiRect::all(Val::Px(2.0)),
+        border_color: Color::srgb(0.3, 0.4, 0.5),
         values_col_width: Some(128.0),
         font_label: ass.load("Ubuntu-B.ttf"),
         font_value: ass.load("Ubuntu-R.ttf"),
@@ -51,7 +54,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
     commands.spawn((
         PerfUiRoot {
             position: PerfUiPosition::TopLeft,
-            z_index: GlobalZIndex(i32::MAX),
+            z_index: Some(GlobalZIndex(i32::MAX)),
             ..root_config.clone()
         },
         PerfUiEntryFPS {
@@ -63,7 +66,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
                 .with_stop(240.0, Color::srgb(0.2, 0.9, 0.4)),
             threshold_highlight: Some(60.0),
             digits: 5,
-            precision: 2,
+            precision: Precision::Fixed(2),
             ..default()
         },
         PerfUiEntryFPSWorst {
@@ -73,7 +76,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
                 .with_stop(240.0, Color::srgb(0.2, 0.9, 0.4)),
             threshold_highlight: Some(60.0),
             digits: 5,
-            precision: 2,
+            precision: Precision::Fixed(2),
             ..default()
         },
         PerfUiEntryFrameTime {
@@ -84,7 +87,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
             ]),
             threshold_highlight: Some(10.0),
             digits: 2,
-            precision: 4,
+            precision: Precision::Fixed(4),
             ..default()
         },
         PerfUiEntryFrameTimeWorst {
@@ -95,7 +98,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
             ]),
             threshold_highlight: Some(10.0),
             digits: 2,
-            precision: 4,
+            precision: Precision::Fixed(4),
             ..default()
         },
     ));
@@ -106,7 +109,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
         PerfUiRoot {
             position: PerfUiPosition::BottomLeft,
             // always display this Perf UI below the other one
-            z_index: GlobalZIndex(i32::MAX - 1),
+            z_index: Some(GlobalZIndex(i32::MAX - 1)),
             ..root_config.clone()
         },
         PerfUiWidgetBar {
@@ -143,7 +146,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
                     (100.0, Color::srgb(1.0, 0.0, 0.0)),
                 ]),
                 threshold_highlight: None,
-                precision: 1,
+                precision: Precision::Fixed(1),
                 ..default()
             })
         },
@@ -163,7 +166,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
                     (100.0, Color::srgb(1.0, 0.0, 0.0)),
                 ]),
                 threshold_highlight: None,
-                precision: 1,
+                precision: Precision::Fixed(1),
                 ..default()
             })
         },
@@ -176,7 +179,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
             display_labels: false,
             position: PerfUiPosition::BottomRight,
             // always display this Perf UI below the other two
-            z_index: GlobalZIndex(i32::MAX - 2),
+            z_index: Some(GlobalZIndex(i32::MAX - 2)),
             ..root_config.clone()
         },
         PerfUiEntryRunningTime {
@@ -199,7 +202,7 @@ fn setup(mut commands: Commands, ass: Res<AssetServer>) {
         PerfUiRoot {
             position: PerfUiPosition::TopRight,
             // always display this Perf UI below the other three
-            z_index: GlobalZIndex(i32::MAX - 3),
+            z_index: Some(GlobalZIndex(i32::MAX - 3)),
             ..root_config.clone()
         },
         PerfUiEntryCursorPosition {
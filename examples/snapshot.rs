@@ -0,0 +1,67 @@
+//! This example shows how to compare the performance before
+//! and after changing a setting, using snapshots.
+//!
+//! Press Space to toggle VSync. The values are captured before
+//! the change, and again a few seconds after, and the difference
+//! is printed to the log.
+
+use bevy::prelude::*;
+use bevy::window::PresentMode;
+use iyes_perf_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // we want Bevy to measure these values for us:
+        .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin)
+        .add_plugins(bevy::diagnostic::EntityCountDiagnosticsPlugin)
+        .add_plugins(bevy::diagnostic::SystemInformationDiagnosticsPlugin)
+        .add_plugins(PerfUiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, compare_vsync)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // spawn a camera to be able to see anything
+    commands.spawn(Camera2d);
+
+    commands.spawn(PerfUiDefaultEntries::default());
+}
+
+/// The snapshot from before the change, and a timer
+/// to wait for the values to settle after it.
+#[derive(Default)]
+struct Comparison {
+    before: Option<(PerfUiSnapshot, Timer)>,
+}
+
+fn compare_vsync(
+    mut comparison: Local<Comparison>,
+    time: Res<Time>,
+    kbd: Res<ButtonInput<KeyCode>>,
+    metrics: Res<PerfUiMetrics>,
+    mut q_window: Query<&mut Window>,
+) {
+    if let Some((before, timer)) = &mut comparison.before {
+        if timer.tick(time.delta()).just_finished() {
+            let after = metrics.snapshot();
+            for (label, before, after, delta) in before.diff(&after) {
+                info!("{label}: {before:.2} -> {after:.2} ({delta:+.2})");
+            }
+            comparison.before = None;
+        }
+        return;
+    }
+    if kbd.just_pressed(KeyCode::Space) {
+        let before = metrics.snapshot();
+        for mut window in &mut q_window {
+            window.present_mode = match window.present_mode {
+                PresentMode::AutoNoVsync => PresentMode::AutoVsync,
+                _ => PresentMode::AutoNoVsync,
+            };
+            info!("Changed present mode to {:?}", window.present_mode);
+        }
+        comparison.before = Some((before, Timer::from_seconds(3.0, TimerMode::Once)));
+    }
+}
@@ -0,0 +1,59 @@
+//! This example shows that the Perf UI follows Bevy's `UiScale`.
+//!
+//! Press `+`/`-` to change the `UiScale`, and `0` to reset it.
+//! The Perf UI stays anchored to its corner and is scaled
+//! together with the rest of the UI.
+
+use bevy::prelude::*;
+use iyes_perf_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // we want Bevy to measure these values for us:
+        .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin)
+        .add_plugins(bevy::diagnostic::EntityCountDiagnosticsPlugin)
+        .add_plugins(PerfUiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, change_ui_scale)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // spawn a camera to be able to see anything
+    commands.spawn(Camera2d);
+
+    // some UI of our own, in the opposite corner, for comparison
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(16.0),
+            left: Val::Px(16.0),
+            ..default()
+        },
+        Text::new("Press +/- to change the UiScale, 0 to reset"),
+    ));
+
+    commands.spawn((
+        PerfUiRoot {
+            position: PerfUiPosition::BottomRight,
+            ..default()
+        },
+        PerfUiDefaultEntries::default(),
+    ));
+}
+
+fn change_ui_scale(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    if kbd.just_pressed(KeyCode::Equal) || kbd.just_pressed(KeyCode::NumpadAdd) {
+        ui_scale.0 = (ui_scale.0 + 0.25).min(4.0);
+    }
+    if kbd.just_pressed(KeyCode::Minus) || kbd.just_pressed(KeyCode::NumpadSubtract) {
+        ui_scale.0 = (ui_scale.0 - 0.25).max(0.25);
+    }
+    if kbd.just_pressed(KeyCode::Digit0) || kbd.just_pressed(KeyCode::Numpad0) {
+        ui_scale.0 = 1.0;
+    }
+}
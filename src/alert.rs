@@ -0,0 +1,136 @@
+//! Alerts: run your own code when a Perf UI value crosses a threshold.
+//!
+//! Register an alert using `app.add_perf_ui_alert(label, threshold, system)`.
+//! Whenever the value of the entry with the given label crosses the threshold,
+//! your system will be run (with the current value as its input) and a
+//! [`PerfUiThresholdEvent`] will be sent.
+//!
+//! Alerts are edge-triggered: they fire once when the threshold is breached,
+//! and will not fire again until the value goes back within the threshold
+//! and then breaches it again.
+//!
+//! Alerts are based on the values collected in [`PerfUiMetrics`], so only
+//! entries that provide a numeric value (via
+//! [`PerfUiEntry::value_as_f64`](crate::entry::PerfUiEntry::value_as_f64))
+//! can trigger alerts.
+//!
+//! The most recent breach for each label is kept, so it can be displayed
+//! using `PerfUiEntryLastSpike`.
+
+use bevy::prelude::*;
+use bevy::core::FrameCount;
+use bevy::ecs::system::SystemId;
+use bevy::utils::{Duration, HashMap};
+
+use crate::metrics::PerfUiMetrics;
+
+/// A threshold to compare Perf UI values against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PerfUiThreshold {
+    /// Breached when the value goes above this.
+    Above(f64),
+    /// Breached when the value goes below this.
+    Below(f64),
+}
+
+impl PerfUiThreshold {
+    /// Is this threshold breached by the given value?
+    pub fn is_breached(&self, value: f64) -> bool {
+        match *self {
+            PerfUiThreshold::Above(t) => value > t,
+            PerfUiThreshold::Below(t) => value < t,
+        }
+    }
+}
+
+/// Event sent whenever the value of an entry crosses one of the registered alert thresholds.
+#[derive(Event, Debug, Clone)]
+pub struct PerfUiThresholdEvent {
+    /// The label of the entry whose value crossed the threshold.
+    pub label: String,
+    /// The value that breached the threshold.
+    pub value: f64,
+    /// The threshold that was breached.
+    pub threshold: PerfUiThreshold,
+    /// The frame number (`FrameCount`) when the threshold was breached.
+    ///
+    /// Useful to find the frame in your logs.
+    pub frame: u32,
+    /// The time (`Time::elapsed`) when the threshold was breached.
+    pub elapsed: Duration,
+}
+
+struct PerfUiAlert {
+    label: String,
+    threshold: PerfUiThreshold,
+    system: SystemId<In<f64>>,
+    breached: bool,
+}
+
+/// Resource to keep track of all registered alerts.
+///
+/// Use `app.add_perf_ui_alert(...)` to add alerts.
+#[derive(Resource, Default)]
+pub struct PerfUiAlerts {
+    alerts: Vec<PerfUiAlert>,
+    last_breach: HashMap<String, PerfUiThresholdEvent>,
+}
+
+impl PerfUiAlerts {
+    /// Add an alert to run the given (already registered) system when
+    /// the entry with the given label crosses the threshold.
+    pub fn add(&mut self, label: impl Into<String>, threshold: PerfUiThreshold, system: SystemId<In<f64>>) {
+        self.alerts.push(PerfUiAlert {
+            label: label.into(),
+            threshold,
+            system,
+            breached: false,
+        });
+    }
+
+    /// Are there any alerts registered?
+    pub fn is_empty(&self) -> bool {
+        self.alerts.is_empty()
+    }
+
+    /// The most recent breach of any alert for the entry with the given label.
+    pub fn last_breach(&self, label: &str) -> Option<&PerfUiThresholdEvent> {
+        self.last_breach.get(label)
+    }
+}
+
+pub(crate) fn rc_any_perf_ui_alerts(
+    alerts: Option<Res<PerfUiAlerts>>,
+) -> bool {
+    alerts.map(|a| !a.is_empty()).unwrap_or(false)
+}
+
+pub(crate) fn check_perf_ui_alerts(
+    mut commands: Commands,
+    mut alerts: ResMut<PerfUiAlerts>,
+    mut evw_threshold: EventWriter<PerfUiThresholdEvent>,
+    metrics: Res<PerfUiMetrics>,
+    frame: Res<FrameCount>,
+    time: Res<Time>,
+) {
+    let PerfUiAlerts { alerts, last_breach } = &mut *alerts;
+    for alert in alerts.iter_mut() {
+        let Some(value) = metrics.get(&alert.label) else {
+            continue;
+        };
+        let breached = alert.threshold.is_breached(value);
+        if breached && !alert.breached {
+            let event = PerfUiThresholdEvent {
+                label: alert.label.clone(),
+                value,
+                threshold: alert.threshold,
+                frame: frame.0,
+                elapsed: time.elapsed(),
+            };
+            last_breach.insert(alert.label.clone(), event.clone());
+            evw_threshold.send(event);
+            commands.run_system_with_input(alert.system, value);
+        }
+        alert.breached = breached;
+    }
+}
@@ -0,0 +1,66 @@
+//! Run conditions based on the Perf UI, for use in your own systems.
+//!
+//! These let you use the performance data as a signal for your game logic,
+//! for example to take a screenshot whenever the framerate drops:
+//!
+//! ```rust
+//! app.add_systems(Update,
+//!     take_screenshot
+//!         .run_if(perf_ui_value_below("FPS", 30.0))
+//! );
+//! ```
+//!
+//! The conditions on values are based on [`PerfUiMetrics`], so the entry
+//! with the given label must be spawned, and must provide a numeric value
+//! (via [`PerfUiEntry::value_as_f64`](crate::entry::PerfUiEntry::value_as_f64)).
+//! If the value is unavailable, the condition is `false`.
+
+use bevy::prelude::*;
+
+use crate::alert::PerfUiThreshold;
+use crate::metrics::PerfUiMetrics;
+use crate::ui::root::PerfUiRoot;
+use crate::PerfUiEnabled;
+
+/// Run condition that is `true` if any Perf UI is currently visible.
+///
+/// A Perf UI is visible if it is enabled (see [`PerfUiEnabled`]),
+/// and its root entity is not hidden (using `Visibility` or `Display::None`).
+pub fn perf_ui_visible() -> impl FnMut(
+    Option<Res<PerfUiEnabled>>,
+    Query<(Option<&Visibility>, Option<&InheritedVisibility>, Option<&Node>), With<PerfUiRoot>>,
+) -> bool + Clone {
+    |enabled, q_root| {
+        crate::rc_perf_ui_enabled(enabled) && crate::ui::rc_any_visible(q_root)
+    }
+}
+
+/// Run condition that is `true` while the value of the entry with the given label breaches the threshold.
+pub fn perf_ui_value_breaches(
+    label: impl Into<String>,
+    threshold: PerfUiThreshold,
+) -> impl FnMut(Option<Res<PerfUiMetrics>>) -> bool + Clone {
+    let label = label.into();
+    move |metrics| {
+        metrics
+            .and_then(|m| m.get(&label))
+            .map(|v| threshold.is_breached(v))
+            .unwrap_or(false)
+    }
+}
+
+/// Run condition that is `true` while the value of the entry with the given label is above the threshold.
+pub fn perf_ui_value_above(
+    label: impl Into<String>,
+    threshold: f64,
+) -> impl FnMut(Option<Res<PerfUiMetrics>>) -> bool + Clone {
+    perf_ui_value_breaches(label, PerfUiThreshold::Above(threshold))
+}
+
+/// Run condition that is `true` while the value of the entry with the given label is below the threshold.
+pub fn perf_ui_value_below(
+    label: impl Into<String>,
+    threshold: f64,
+) -> impl FnMut(Option<Res<PerfUiMetrics>>) -> bool + Clone {
+    perf_ui_value_breaches(label, PerfUiThreshold::Below(threshold))
+}
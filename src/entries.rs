@@ -12,6 +12,9 @@ pub mod prelude {
         PerfUiEntryFrameTime,
         PerfUiEntryFPSWorst,
         PerfUiEntryFrameTimeWorst,
+        PerfUiEntryGpuFrameTime,
+        PerfUiEntryFrameBudget,
+        PerfUiEntryDiagnostic,
         PerfUiEntryFrameCount,
         PerfUiEntryEntityCount,
     };
@@ -21,49 +24,350 @@ pub mod prelude {
         PerfUiEntryCpuUsage,
         PerfUiEntryMemUsage,
     };
+    #[cfg(feature = "sysinfo")]
+    pub use super::cpu::PerfUiEntryCpuFrequency;
+
+    pub use super::alert::PerfUiEntryLastSpike;
+
+    pub use super::alloc::{
+        PerfUiEntryAllocations,
+        PerfUiAllocations,
+        PerfUiCountingAllocator,
+    };
+
+    pub use super::assets::{
+        PerfUiEntryAssetsLoading,
+        PerfUiTrackedAssets,
+        PerfUiEntryAssetMemory,
+        PerfUiAssetSize,
+    };
+
+    #[cfg(feature = "animation")]
+    pub use super::animation::PerfUiEntryActiveAnimations;
+
+    #[cfg(feature = "audio")]
+    pub use super::audio::{
+        PerfUiEntryAudioLatency,
+        PerfUiAudioStats,
+    };
+
+    pub use super::event::PerfUiEntryEventCount;
+
+    pub use super::reflect::{
+        PerfUiEntryReflect,
+        PerfUiReflectValues,
+    };
+
+    pub use super::span::{
+        PerfUiEntrySpan,
+        PerfUiSpanTimer,
+        PerfUiSpanGuard,
+        perf_ui_record_span,
+    };
+    pub use crate::perf_ui_span;
+
+    pub use super::render::PerfUiEntryRenderBackend;
+
+    pub use super::records::{
+        PerfUiEntryRecord,
+        PerfUiRecords,
+        PerfUiRecord,
+    };
+    #[cfg(feature = "serde")]
+    pub use super::records::PerfUiRecordsFilePlugin;
+
+    pub use super::schedule::{
+        PerfUiEntryScheduleTime,
+        PerfUiScheduleTimingPlugin,
+    };
+
+    pub use super::input::{
+        PerfUiEntryInputLatency,
+        PerfUiEntryGamepadCount,
+        PerfUiInputLatency,
+    };
+
+    pub use super::misc::{
+        PerfUiEntryStatic,
+        PerfUiEntryVersion,
+        PerfUiEntryChannel,
+        PerfUiEntryManual,
+    };
 
     pub use super::time::{
         PerfUiEntryClock,
         PerfUiEntryRunningTime,
+        PerfUiEntryRunningTimeReal,
+        PerfUiEntryRunningTimeVirtual,
+        PerfUiEntryRunningTimeFixed,
+        PerfUiEntryRunningTimeGeneric,
+        PerfUiTimeContext,
+        PerfUiEntryTimeSince,
+        PerfUiMark,
+        PerfUiEntryLapTimer,
+        PerfUiLap,
+        PerfUiLaps,
+        PerfUiLapTimes,
+        PerfUiEntryInterval,
+        PerfUiStart,
+        PerfUiStop,
+        PerfUiIntervals,
+        PerfUiIntervalState,
         PerfUiEntryFixedTimeStep,
         PerfUiEntryFixedOverstep,
+        PerfUiEntryFixedSteps,
+        PerfUiFixedSteps,
     };
     pub use super::window::{
         PerfUiEntryWindowResolution,
         PerfUiEntryWindowScaleFactor,
+        PerfUiEntryRefreshRate,
+        PerfUiEntryWindowCount,
         PerfUiEntryWindowMode,
         PerfUiEntryWindowPresentMode,
         PerfUiEntryCursorPosition,
     };
 }
 
+pub mod alert;
+pub mod alloc;
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod assets;
+#[cfg(feature = "sysinfo")]
+pub mod cpu;
+#[cfg(feature = "audio")]
+pub mod audio;
 pub mod diagnostics;
+pub mod event;
+pub mod input;
+pub mod misc;
+pub mod records;
+pub mod reflect;
+pub mod render;
+pub mod schedule;
+pub mod span;
 pub mod time;
 pub mod window;
 
+pub(crate) fn predefined_entries_metrics_plugin(app: &mut App) {
+    app.add_event::<PerfUiMark>();
+    app.init_resource::<time::PerfUiMarks>();
+    app.add_systems(Update,
+        time::record_perf_ui_marks
+            .before(crate::PerfUiSet::Update)
+    );
+    app.add_event::<PerfUiLap>();
+    app.init_resource::<PerfUiLaps>();
+    app.add_systems(Update,
+        time::record_perf_ui_laps
+            .before(crate::PerfUiSet::Update)
+    );
+    app.add_event::<PerfUiStart>();
+    app.add_event::<PerfUiStop>();
+    app.init_resource::<PerfUiIntervals>();
+    app.add_systems(Update,
+        time::record_perf_ui_intervals
+            .before(crate::PerfUiSet::Update)
+    );
+    app.init_resource::<PerfUiAllocations>();
+    app.add_systems(First, alloc::record_perf_ui_allocations);
+    app.init_resource::<PerfUiInputLatency>();
+    app.add_systems(First, input::detect_perf_ui_input);
+    app.add_systems(Last, input::record_perf_ui_input_latency);
+    app.init_resource::<PerfUiReflectValues>();
+    app.add_systems(Update,
+        reflect::read_perf_ui_reflect_values
+            .run_if(any_with_component::<PerfUiEntryReflect>)
+            .before(crate::PerfUiSet::Update)
+    );
+    app.init_resource::<PerfUiSpanTimer>();
+    app.add_systems(First, span::record_perf_ui_spans);
+    app.init_resource::<PerfUiRecords>();
+    app.add_systems(Update,
+        records::update_perf_ui_records
+            .run_if(any_with_component::<PerfUiEntryRecord>)
+            .run_if(crate::rc_perf_ui_enabled)
+            .after(crate::metrics::finish_perf_ui_metrics)
+    );
+    app.init_resource::<PerfUiFixedSteps>();
+    app.add_systems(FixedFirst, time::count_perf_ui_fixed_steps);
+    app.add_systems(Update,
+        time::record_perf_ui_fixed_steps
+            .before(crate::PerfUiSet::Update)
+    );
+
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFPS>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFrameTime>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFPSWorst>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFrameTimeWorst>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryGpuFrameTime>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFrameBudget>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryDiagnostic>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryScheduleTime>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntrySpan>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFrameCount>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryEntityCount>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryAllocations>();
+
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_metrics::<PerfUiEntryCpuUsage>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_metrics::<PerfUiEntryMemUsage>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_metrics::<PerfUiEntryCpuFrequency>();
+
+    app.init_resource::<PerfUiTrackedAssets>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryAssetsLoading>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryAssetMemory<Image>>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryAssetMemory<Mesh>>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryInputLatency>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryGamepadCount>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryStatic>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryVersion>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRenderBackend>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryChannel>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryManual>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRecord>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryLastSpike>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryReflect>();
+    #[cfg(feature = "audio")]
+    app.init_resource::<PerfUiAudioStats>();
+    #[cfg(feature = "audio")]
+    app.add_perf_ui_entry_metrics::<PerfUiEntryAudioLatency>();
+    #[cfg(feature = "animation")]
+    app.add_perf_ui_entry_metrics::<PerfUiEntryActiveAnimations>();
+
+    app.add_perf_ui_entry_metrics::<PerfUiEntryClock>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRunningTime>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRunningTimeReal>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRunningTimeVirtual>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRunningTimeFixed>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryTimeSince>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryLapTimer>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryInterval>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFixedTimeStep>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFixedOverstep>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryFixedSteps>();
+
+    app.add_perf_ui_entry_metrics::<PerfUiEntryWindowResolution>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryWindowScaleFactor>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryRefreshRate>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryWindowCount>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryWindowMode>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryWindowPresentMode>();
+    app.add_perf_ui_entry_metrics::<PerfUiEntryCursorPosition>();
+}
+
 pub(crate) fn predefined_entries_plugin(app: &mut App) {
+    app.add_systems(Update,
+        diagnostics::clear_perf_ui_latched_frame_time
+            .run_if(any_with_component::<PerfUiEntryFrameTime>)
+            .before(crate::PerfUiSet::Update)
+    );
+
     app.add_perf_ui_simple_entry::<PerfUiEntryFPS>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFrameTime>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFPSWorst>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFrameTimeWorst>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryGpuFrameTime>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryFrameBudget>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryDiagnostic>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryScheduleTime>();
+    app.add_perf_ui_simple_entry::<PerfUiEntrySpan>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFrameCount>();
     app.add_perf_ui_simple_entry::<PerfUiEntryEntityCount>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryAllocations>();
 
     #[cfg(feature = "sysinfo")]
     app.add_perf_ui_simple_entry::<PerfUiEntryCpuUsage>();
     #[cfg(feature = "sysinfo")]
     app.add_perf_ui_simple_entry::<PerfUiEntryMemUsage>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_simple_entry::<PerfUiEntryCpuFrequency>();
+
+    app.add_perf_ui_simple_entry::<PerfUiEntryAssetsLoading>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryAssetMemory<Image>>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryAssetMemory<Mesh>>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryInputLatency>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryGamepadCount>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryStatic>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryVersion>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRenderBackend>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryChannel>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryManual>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRecord>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryLastSpike>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryReflect>();
+    #[cfg(feature = "audio")]
+    app.add_perf_ui_simple_entry::<PerfUiEntryAudioLatency>();
+    #[cfg(feature = "animation")]
+    app.add_perf_ui_simple_entry::<PerfUiEntryActiveAnimations>();
 
     app.add_perf_ui_simple_entry::<PerfUiEntryClock>();
     app.add_perf_ui_simple_entry::<PerfUiEntryRunningTime>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRunningTimeReal>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRunningTimeVirtual>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRunningTimeFixed>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryTimeSince>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryLapTimer>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryInterval>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFixedTimeStep>();
     app.add_perf_ui_simple_entry::<PerfUiEntryFixedOverstep>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryFixedSteps>();
 
     app.add_perf_ui_simple_entry::<PerfUiEntryWindowResolution>();
     app.add_perf_ui_simple_entry::<PerfUiEntryWindowScaleFactor>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryRefreshRate>();
+    app.add_perf_ui_simple_entry::<PerfUiEntryWindowCount>();
     app.add_perf_ui_simple_entry::<PerfUiEntryWindowMode>();
     app.add_perf_ui_simple_entry::<PerfUiEntryWindowPresentMode>();
     app.add_perf_ui_simple_entry::<PerfUiEntryCursorPosition>();
+
+    app.add_perf_ui_entry_info::<PerfUiEntryFPS>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFrameTime>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFPSWorst>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFrameTimeWorst>();
+    app.add_perf_ui_entry_info::<PerfUiEntryGpuFrameTime>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFrameBudget>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFrameCount>();
+    app.add_perf_ui_entry_info::<PerfUiEntryEntityCount>();
+    app.add_perf_ui_entry_info::<PerfUiEntryAllocations>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_info::<PerfUiEntryCpuUsage>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_info::<PerfUiEntryMemUsage>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_entry_info::<PerfUiEntryCpuFrequency>();
+    app.add_perf_ui_entry_info::<PerfUiEntryAssetsLoading>();
+    app.add_perf_ui_entry_info::<PerfUiEntryAssetMemory<Image>>();
+    app.add_perf_ui_entry_info::<PerfUiEntryAssetMemory<Mesh>>();
+    app.add_perf_ui_entry_info::<PerfUiEntryInputLatency>();
+    app.add_perf_ui_entry_info::<PerfUiEntryGamepadCount>();
+    app.add_perf_ui_entry_info::<PerfUiEntryStatic>();
+    app.add_perf_ui_entry_info::<PerfUiEntryVersion>();
+    app.add_perf_ui_entry_info::<PerfUiEntryRenderBackend>();
+    app.add_perf_ui_entry_info::<PerfUiEntryManual>();
+    #[cfg(feature = "audio")]
+    app.add_perf_ui_entry_info::<PerfUiEntryAudioLatency>();
+    #[cfg(feature = "animation")]
+    app.add_perf_ui_entry_info::<PerfUiEntryActiveAnimations>();
+    app.add_perf_ui_entry_info::<PerfUiEntryClock>();
+    app.add_perf_ui_entry_info::<PerfUiEntryRunningTime>();
+    app.add_perf_ui_entry_info::<PerfUiEntryTimeSince>();
+    app.add_perf_ui_entry_info::<PerfUiEntryLapTimer>();
+    app.add_perf_ui_entry_info::<PerfUiEntryInterval>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFixedTimeStep>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFixedOverstep>();
+    app.add_perf_ui_entry_info::<PerfUiEntryFixedSteps>();
+    app.add_perf_ui_entry_info::<PerfUiEntryWindowResolution>();
+    app.add_perf_ui_entry_info::<PerfUiEntryWindowScaleFactor>();
+    app.add_perf_ui_entry_info::<PerfUiEntryRefreshRate>();
+    app.add_perf_ui_entry_info::<PerfUiEntryWindowCount>();
+    app.add_perf_ui_entry_info::<PerfUiEntryWindowMode>();
+    app.add_perf_ui_entry_info::<PerfUiEntryWindowPresentMode>();
+    app.add_perf_ui_entry_info::<PerfUiEntryCursorPosition>();
+
 }
 
 /// Bundle for a Perf UI with all entry types provided by `iyes_perf_ui`.
@@ -96,6 +400,7 @@ pub struct PerfUiAllEntries {
     pub fps_worst: PerfUiEntryFPSWorst,
     pub frametime: PerfUiEntryFrameTime,
     pub frametime_worst: PerfUiEntryFrameTimeWorst,
+    pub frame_budget: PerfUiEntryFrameBudget,
     pub frame_count: PerfUiEntryFrameCount,
     pub entity_count: PerfUiEntryEntityCount,
     #[cfg(feature = "sysinfo")]
@@ -104,13 +409,16 @@ pub struct PerfUiAllEntries {
     pub mem_usage: PerfUiEntryMemUsage,
     pub fixed_timestep: PerfUiEntryFixedTimeStep,
     pub fixed_overstep: PerfUiEntryFixedOverstep,
+    pub fixed_steps: PerfUiEntryFixedSteps,
     pub time_running: PerfUiEntryRunningTime,
     pub time_clock: PerfUiEntryClock,
     pub cursor_position: PerfUiEntryCursorPosition,
     pub window_resolution: PerfUiEntryWindowResolution,
     pub window_scale_factor: PerfUiEntryWindowScaleFactor,
+    pub refresh_rate: PerfUiEntryRefreshRate,
     pub window_mode: PerfUiEntryWindowMode,
     pub window_present_mode: PerfUiEntryWindowPresentMode,
+    pub window_count: PerfUiEntryWindowCount,
 }
 
 /// Bundle for a Perf UI with some of the entry types provided by `iyes_perf_ui`.
@@ -199,6 +507,7 @@ pub struct PerfUiSystemEntries {
 pub struct PerfUiFixedTimeEntries {
     pub fixed_timestep: PerfUiEntryFixedTimeStep,
     pub fixed_overstep: PerfUiEntryFixedOverstep,
+    pub fixed_steps: PerfUiEntryFixedSteps,
 }
 
 /// All entries related to windowing.
@@ -215,6 +524,8 @@ pub struct PerfUiWindowEntries {
     pub cursor_position: PerfUiEntryCursorPosition,
     pub window_resolution: PerfUiEntryWindowResolution,
     pub window_scale_factor: PerfUiEntryWindowScaleFactor,
+    pub refresh_rate: PerfUiEntryRefreshRate,
     pub window_mode: PerfUiEntryWindowMode,
     pub window_present_mode: PerfUiEntryWindowPresentMode,
+    pub window_count: PerfUiEntryWindowCount,
 }
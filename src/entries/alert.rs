@@ -0,0 +1,196 @@
+//! Perf UI Entries for info about alerts.
+
+use std::sync::OnceLock;
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::alert::PerfUiAlerts;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display when the most recent spike of another entry happened.
+///
+/// A spike is a breach of an alert threshold (see [`crate::alert`]), so
+/// an alert must be registered for the other entry, using
+/// `app.add_perf_ui_alert(...)`. The entry displays the frame number
+/// (`FrameCount`) and time (`Time::elapsed`) of the most recent breach
+/// (like "#12345 @ 1:23.456"), to help find it in your logs.
+///
+/// The value is unavailable until the first breach.
+///
+/// ```rust
+/// app.add_perf_ui_alert("Frame Time", PerfUiThreshold::Above(33.3), |In(ms): In<f64>| {
+///     warn!("Frame Time spike: {} ms", ms);
+/// });
+///
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryFrameTime::default(),
+///     PerfUiEntryLastSpike::new("Frame Time"),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryLastSpike {
+    /// Custom label. If empty (default), "Last <source> Spike" will be used.
+    pub label: String,
+    /// The label of the entry whose alerts to display.
+    pub source: String,
+    /// Display the time alongside the frame number.
+    ///
+    /// Default: `true`
+    pub display_time: bool,
+    /// Number of digits to display for the frame number.
+    ///
+    /// Default: `6`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part
+    /// of the time.
+    ///
+    /// Default: `3` (milliseconds)
+    pub precision: u8,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal cache of the default label. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub default_label: LastSpikeLabelCache,
+}
+
+/// The default label of a [`PerfUiEntryLastSpike`], once it has been computed.
+///
+/// Cloning gives an empty cache, so it is computed again.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct LastSpikeLabelCache(OnceLock<String>);
+
+impl Clone for LastSpikeLabelCache {
+    fn clone(&self) -> Self {
+        LastSpikeLabelCache::default()
+    }
+}
+
+impl Default for PerfUiEntryLastSpike {
+    fn default() -> Self {
+        PerfUiEntryLastSpike {
+            label: String::new(),
+            source: String::new(),
+            display_time: true,
+            digits: 6,
+            precision: 3,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            default_label: LastSpikeLabelCache::default(),
+        }
+    }
+}
+
+impl PerfUiEntryLastSpike {
+    /// Create an entry to display the last spike of the entry with the given label.
+    pub fn new(source: impl Into<String>) -> Self {
+        PerfUiEntryLastSpike {
+            source: source.into(),
+            ..default()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryLastSpike {
+    /// The frame number and the time.
+    type Value = (u32, Duration);
+    type SystemParam = SRes<PerfUiAlerts>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            self.default_label.0.get_or_init(|| format!("Last {} Spike", self.source))
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        // "#" + frame number
+        let w = 1 + width_hint_pretty_int(self.digits);
+        if self.display_time {
+            // " @ " + time
+            w + 3 + width_hint_pretty_time(self.precision)
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        alerts: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        alerts.last_breach(&self.source)
+            .map(|event| (event.frame, event.elapsed))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        let (frame, elapsed) = *value;
+        let mut s = format!("#{}", format_pretty_int(self.digits, frame as i64).trim_start());
+        if self.display_time {
+            s.push_str(" @ ");
+            s.push_str(format_pretty_time(self.precision, elapsed).trim_start());
+        }
+        s
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
@@ -0,0 +1,347 @@
+//! Perf UI Entries for info about memory allocations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// The total number of allocations made through [`PerfUiCountingAllocator`].
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations, for [`PerfUiEntryAllocations`].
+///
+/// Wraps another allocator (the system allocator, by default), and
+/// counts every allocation (and reallocation) made through it. To use it,
+/// set it as the global allocator of your app:
+///
+/// ```rust
+/// #[global_allocator]
+/// static ALLOC: PerfUiCountingAllocator = PerfUiCountingAllocator::new();
+/// ```
+///
+/// Counting has a small cost for every allocation, so you might want to only
+/// do this in development builds.
+pub struct PerfUiCountingAllocator<A = System> {
+    inner: A,
+}
+
+impl PerfUiCountingAllocator<System> {
+    /// Count allocations made through the system allocator.
+    pub const fn new() -> Self {
+        PerfUiCountingAllocator {
+            inner: System,
+        }
+    }
+
+    /// The total number of allocations so far.
+    ///
+    /// Always `0` if a `PerfUiCountingAllocator` is not the global allocator.
+    pub fn total_allocations() -> u64 {
+        ALLOCATIONS.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for PerfUiCountingAllocator<System> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> PerfUiCountingAllocator<A> {
+    /// Count allocations made through another allocator.
+    pub const fn with_allocator(inner: A) -> Self {
+        PerfUiCountingAllocator {
+            inner,
+        }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for PerfUiCountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        self.inner.alloc(layout)
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        self.inner.alloc_zeroed(layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        self.inner.realloc(ptr, layout, new_size)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout)
+    }
+}
+
+/// Resource with the number of allocations per frame, for [`PerfUiEntryAllocations`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiAllocations {
+    last_total: u64,
+    last_frame: Option<u64>,
+}
+
+impl PerfUiAllocations {
+    /// The number of allocations during the previous frame.
+    ///
+    /// `None` if a [`PerfUiCountingAllocator`] is not the global allocator.
+    pub fn last_frame(&self) -> Option<u64> {
+        self.last_frame
+    }
+}
+
+/// Record the number of allocations made since the previous frame.
+pub(crate) fn record_perf_ui_allocations(
+    mut allocations: ResMut<PerfUiAllocations>,
+) {
+    let total = ALLOCATIONS.load(Ordering::Relaxed);
+    // if nothing was ever counted, the allocator is not installed
+    // (any Bevy app allocates long before this runs)
+    if total == 0 {
+        allocations.last_frame = None;
+        return;
+    }
+    if allocations.last_total != 0 {
+        allocations.last_frame = Some(total - allocations.last_total);
+    }
+    allocations.last_total = total;
+}
+
+/// Perf UI Entry to display the number of memory allocations per frame.
+///
+/// Requires [`PerfUiCountingAllocator`] to be set as the `#[global_allocator]`.
+/// Otherwise, the value will be unavailable.
+///
+/// Displays the count during the previous (complete) frame. Allocations
+/// from all threads are counted, including Bevy's own and those from
+/// other libraries.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryAllocations {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 10-100-1000.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<u64>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display.
+    ///
+    /// Default: `5`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of allocations.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of allocations, before the change
+    /// (like "1240 (+12)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryAllocations {
+    fn default() -> Self {
+        PerfUiEntryAllocations {
+            label: String::new(),
+            color_gradient: ColorGradient::new_preset_gyr(10.0, 100.0, 1000.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 5,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryAllocations {
+    type Value = u64;
+    type SystemParam = SRes<PerfUiAllocations>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Allocations"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
+    }
+    fn update_value(
+        &self,
+        allocations: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let count = allocations.last_frame()?;
+        if self.show_delta {
+            self.delta.push(count as i64);
+        }
+        Some(count)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryAllocations {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as u64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0)
+    }
+}
@@ -0,0 +1,249 @@
+//! Perf UI Entries for info about animations.
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SQuery;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display the number of `AnimationPlayer`s that are playing.
+///
+/// A player counts as playing if at least one of its animations is
+/// neither paused nor finished. Useful to debug the cost of animation-heavy scenes.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryActiveAnimations {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Also count players whose animations are all paused.
+    ///
+    /// Default: `false`
+    pub count_paused: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: empty (disabled)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<u32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of animations.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of animations, before the change
+    /// (like "40 (+2)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryActiveAnimations {
+    fn default() -> Self {
+        PerfUiEntryActiveAnimations {
+            label: String::new(),
+            count_paused: false,
+            color_gradient: ColorGradient::default(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 4,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryActiveAnimations {
+    type Value = u32;
+    type SystemParam = SQuery<&'static AnimationPlayer>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Active Animations"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
+    }
+    fn update_value(
+        &self,
+        q_player: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let count = q_player.iter()
+            .filter(|player| {
+                player.playing_animations().any(|(_, animation)| {
+                    !animation.is_finished() && (self.count_paused || !animation.is_paused())
+                })
+            })
+            .count();
+        if self.show_delta {
+            self.delta.push(count as i64);
+        }
+        Some(count as u32)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryActiveAnimations {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as u32)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0)
+    }
+}
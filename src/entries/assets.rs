@@ -0,0 +1,466 @@
+//! Perf UI Entries for info about asset loading and memory.
+
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use bevy::prelude::*;
+use bevy::asset::{LoadState, UntypedAssetId};
+use bevy::render::mesh::Indices;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+use super::event::short_type_name;
+
+/// Resource to keep track of which assets should be counted by [`PerfUiEntryAssetsLoading`].
+///
+/// Bevy does not provide a way to query the load state of all assets,
+/// so you must tell Perf UI which assets you care about. Add the handles
+/// of your assets when you start loading them:
+///
+/// ```rust
+/// fn load_my_assets(
+///     server: Res<AssetServer>,
+///     mut tracked: ResMut<PerfUiTrackedAssets>,
+/// ) {
+///     let handle: Handle<Image> = server.load("my_image.png");
+///     tracked.add(&handle);
+/// }
+/// ```
+///
+/// Only the asset IDs are stored, so tracking an asset does not keep it alive.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiTrackedAssets {
+    ids: Vec<UntypedAssetId>,
+}
+
+impl PerfUiTrackedAssets {
+    /// Start tracking an asset.
+    pub fn add(&mut self, id: impl Into<UntypedAssetId>) {
+        self.ids.push(id.into());
+    }
+
+    /// Stop tracking all assets.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+
+    /// Iterate over the IDs of all tracked assets.
+    pub fn iter(&self) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        self.ids.iter().copied()
+    }
+}
+
+/// Perf UI Entry to display how many assets are still loading.
+///
+/// Only counts the assets added to [`PerfUiTrackedAssets`]. Assets that
+/// have finished loading, have failed to load, or have been unloaded,
+/// are not counted. If nothing is loading, displays 0.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryAssetsLoading {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Also display the percentage of tracked assets that are done loading.
+    ///
+    /// Default: `false`
+    pub display_percentage: bool,
+    /// Number of digits to display.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of assets still loading.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of assets still loading,
+    /// before the change (like "12 (-3)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryAssetsLoading {
+    fn default() -> Self {
+        PerfUiEntryAssetsLoading {
+            label: String::new(),
+            display_percentage: false,
+            digits: 4,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryAssetsLoading {
+    /// (number of assets still loading, total number of tracked assets)
+    type Value = (u32, u32);
+    type SystemParam = (
+        SRes<AssetServer>,
+        SRes<PerfUiTrackedAssets>,
+    );
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Assets Loading"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        let w = DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value);
+        if self.display_percentage {
+            w + 7
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        (server, tracked): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let mut pending = 0;
+        let mut total = 0;
+        for id in tracked.iter() {
+            total += 1;
+            if matches!(
+                server.get_load_state(id),
+                Some(LoadState::NotLoaded | LoadState::Loading)
+            ) {
+                pending += 1;
+            }
+        }
+        if self.show_delta {
+            self.delta.push(pending as i64);
+        }
+        Some((pending, total))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let &(pending, total) = value;
+        let mut s = self.delta.format_value(self.digits, pending as i64, self.show_delta, self.show_delta_with_value);
+        if self.display_percentage {
+            let done = if total == 0 {
+                100.0
+            } else {
+                (total - pending) as f32 / total as f32 * 100.0
+            };
+            s.push_str(&format!(" ({:>3.0}%)", done));
+        }
+        s
+    }
+    fn value_as_f64(
+        &self,
+        &(pending, _): &Self::Value,
+    ) -> Option<f64> {
+        Some(pending as f64)
+    }
+}
+
+/// Asset types whose memory usage can be displayed by [`PerfUiEntryAssetMemory`].
+///
+/// Implemented for `Image` and `Mesh`. Implement it for your own asset
+/// types, and register the entry for them:
+///
+/// ```rust
+/// impl PerfUiAssetSize for MyLevel {
+///     fn perf_ui_asset_size(&self) -> Option<usize> {
+///         Some(self.tiles.len() * std::mem::size_of::<Tile>())
+///     }
+/// }
+///
+/// app.add_perf_ui_simple_entry::<PerfUiEntryAssetMemory<MyLevel>>();
+/// ```
+pub trait PerfUiAssetSize: Asset {
+    /// An estimate of how many bytes of memory the asset uses.
+    ///
+    /// Return `None` if it is unknown. Such assets are not counted.
+    fn perf_ui_asset_size(&self) -> Option<usize>;
+}
+
+impl PerfUiAssetSize for Image {
+    fn perf_ui_asset_size(&self) -> Option<usize> {
+        Some(self.data.len())
+    }
+}
+
+impl PerfUiAssetSize for Mesh {
+    fn perf_ui_asset_size(&self) -> Option<usize> {
+        let indices = match self.indices() {
+            Some(Indices::U16(indices)) => indices.len() * 2,
+            Some(Indices::U32(indices)) => indices.len() * 4,
+            None => 0,
+        };
+        Some(self.get_vertex_buffer_size() + indices)
+    }
+}
+
+/// Perf UI Entry to display the total memory used by all assets of type `A`.
+///
+/// Useful to catch texture/mesh memory bloat. The sizes are estimates,
+/// from [`PerfUiAssetSize`]. Only the data kept in the main world is
+/// counted: assets that have been moved to the GPU (with `RenderAssetUsages`
+/// that do not include `MAIN_WORLD`) no longer exist on the CPU side, and
+/// are not counted.
+///
+/// The value is unavailable if the asset type has not been added to the app,
+/// or if none of its assets provide their size.
+///
+/// Registered for `Image` and `Mesh`. For other asset types, it must be
+/// registered with `app.add_perf_ui_simple_entry::<PerfUiEntryAssetMemory<MyAsset>>()`.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, bound = ""))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryAssetMemory<A: PerfUiAssetSize> {
+    /// Custom label. If empty (default), "<asset type> Memory" will be used.
+    pub label: String,
+    /// Highlight the value if it is above this threshold (in bytes).
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<u64>,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `2`
+    pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Adding up the sizes of all assets has a cost, if there are many.
+    ///
+    /// Default: once per second
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal cache of the default label. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub default_label: AssetMemoryLabelCache,
+    /// The asset type. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub _asset: PhantomData<A>,
+}
+
+/// The default label of a [`PerfUiEntryAssetMemory`], once it has been computed.
+///
+/// Cloning gives an empty cache, so it is computed again.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct AssetMemoryLabelCache(OnceLock<String>);
+
+impl Clone for AssetMemoryLabelCache {
+    fn clone(&self) -> Self {
+        AssetMemoryLabelCache::default()
+    }
+}
+
+impl<A: PerfUiAssetSize> Default for PerfUiEntryAssetMemory<A> {
+    fn default() -> Self {
+        PerfUiEntryAssetMemory {
+            label: String::new(),
+            threshold_highlight: None,
+            precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: Some(PerfUiThrottle::new(Duration::from_secs(1))),
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            default_label: AssetMemoryLabelCache::default(),
+            _asset: PhantomData,
+        }
+    }
+}
+
+impl<A: PerfUiAssetSize> PerfUiEntry for PerfUiEntryAssetMemory<A> {
+    /// Total size in bytes
+    type Value = u64;
+    type SystemParam = Option<SRes<Assets<A>>>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            self.default_label.0.get_or_init(|| {
+                format!("{} Memory", short_type_name(std::any::type_name::<A>()))
+            })
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        width_hint_pretty_bytes(self.precision)
+    }
+    fn update_value(
+        &self,
+        assets: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let assets = assets.as_ref()?;
+        let mut total = 0;
+        let mut any_known = assets.is_empty();
+        for (_, asset) in assets.iter() {
+            if let Some(size) = asset.perf_ui_asset_size() {
+                total += size as u64;
+                any_known = true;
+            }
+        }
+        any_known.then_some(total)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        format_pretty_bytes(self.precision, *value)
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| *value > t)
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
@@ -0,0 +1,266 @@
+//! Perf UI Entries for info about audio output.
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Resource with statistics about the audio output, for [`PerfUiEntryAudioLatency`].
+///
+/// Bevy's built-in audio backend does not currently report its latency
+/// or buffer underruns. If your audio backend (or your own audio code)
+/// can measure them, write them into this resource every frame. Any
+/// value that is `None` will be displayed as unavailable.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiAudioStats {
+    /// The current output latency (time from submitting a sample, until it is played).
+    pub latency: Option<Duration>,
+    /// The total number of buffer underruns so far.
+    pub underruns: Option<u64>,
+}
+
+/// Perf UI Entry to display the audio output latency.
+///
+/// Displays the latency in *milliseconds*, and optionally the number
+/// of buffer underruns. The data comes from the [`PerfUiAudioStats`] resource.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryAudioLatency {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the unit ("ms") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Also display the number of buffer underruns, if available.
+    ///
+    /// Default: `true`
+    pub display_underruns: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 10ms-30ms-60ms.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if latency is above this threshold.
+    ///
+    /// Default: `100.0`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `3`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(1)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryAudioLatency {
+    fn default() -> Self {
+        PerfUiEntryAudioLatency {
+            label: String::new(),
+            display_units: true,
+            display_underruns: true,
+            color_gradient: ColorGradient::new_preset_gyr(10.0, 30.0, 60.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(100.0),
+            goal: MetricGoal::LowerIsBetter,
+            digits: 3,
+            precision: Precision::Fixed(1),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryAudioLatency {
+    /// (latency in ms, number of underruns)
+    type Value = (f64, Option<u64>);
+    type SystemParam = SRes<PerfUiAudioStats>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Audio Latency"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        let mut w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w += 3;
+        }
+        if self.display_underruns {
+            // " (NNNN)"
+            w += 7;
+        }
+        w
+    }
+    fn update_value(
+        &self,
+        stats: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let latency = stats.latency?.as_secs_f64() * 1000.0;
+        Some((latency, stats.underruns))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let &(latency, underruns) = value;
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, latency);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        if self.display_underruns {
+            if let Some(underruns) = underruns {
+                s.push_str(&format!(" ({})", underruns));
+            }
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        &(latency, _): &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(latency as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        &(latency, _): &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(latency as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        &(latency, _): &Self::Value,
+    ) -> Option<f64> {
+        Some(latency)
+    }
+}
@@ -0,0 +1,289 @@
+//! Perf UI Entries for info about the CPU.
+
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display the current CPU frequency (clock speed).
+///
+/// Displays the average over all CPU cores, in MHz or GHz (whichever
+/// is more readable). Useful to notice thermal throttling, on devices
+/// that lower their clock speed under load.
+///
+/// Reading the frequency has a small cost, so by default, it is only
+/// updated once per second (see `throttle`). The value is unavailable
+/// on platforms where the frequency cannot be read.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryCpuFrequency {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the unit ("MHz"/"GHz") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Enable color based on value (in MHz).
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: empty (disabled)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is below this threshold (in MHz).
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::HigherIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be (in MHz)?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display for the fractional (after the decimal point) part,
+    /// when displaying GHz.
+    ///
+    /// Default: `2`
+    pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: once per second
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for reading the frequency. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub state: CpuFrequencyState,
+}
+
+/// The `sysinfo` handle of a [`PerfUiEntryCpuFrequency`], and the value
+/// it last read, with the time of the frame when it was read.
+///
+/// Cloning gives an empty state, so it is initialized again.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct CpuFrequencyState(Mutex<Option<(Box<System>, Duration, Option<f64>)>>);
+
+impl Clone for CpuFrequencyState {
+    fn clone(&self) -> Self {
+        CpuFrequencyState::default()
+    }
+}
+
+impl Default for PerfUiEntryCpuFrequency {
+    fn default() -> Self {
+        PerfUiEntryCpuFrequency {
+            label: String::new(),
+            display_units: true,
+            color_gradient: ColorGradient::default(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::HigherIsBetter,
+            max_value_hint: None,
+            precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: Some(PerfUiThrottle::new(Duration::from_secs(1))),
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            state: CpuFrequencyState::default(),
+        }
+    }
+}
+
+/// The average frequency of all CPU cores, in MHz.
+fn average_frequency(system: &System) -> Option<f64> {
+    let cpus = system.cpus();
+    let total: u64 = cpus.iter().map(|cpu| cpu.frequency()).sum();
+    // zero if unsupported on this platform
+    (total > 0).then(|| total as f64 / cpus.len() as f64)
+}
+
+impl PerfUiEntry for PerfUiEntryCpuFrequency {
+    type Value = f64;
+    type SystemParam = SRes<Time<Real>>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "CPU Frequency"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        // "999" (MHz) or "9.99" (GHz)
+        let w = width_hint_pretty_float(1, self.precision).max(3);
+        if self.display_units {
+            w + 4
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        time: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let mut state = self.state.0.lock().ok()?;
+        let now = time.elapsed();
+        match &mut *state {
+            // the value is already known on this frame
+            Some((_, last, value)) if *last == now => *value,
+            Some((system, last, value)) => {
+                system.refresh_cpu_frequency();
+                *last = now;
+                *value = average_frequency(system);
+                *value
+            }
+            None => {
+                let system = Box::new(System::new_with_specifics(
+                    RefreshKind::new().with_cpu(CpuRefreshKind::new().with_frequency())
+                ));
+                let value = average_frequency(&system);
+                *state = Some((system, now, value));
+                value
+            }
+        }
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let (mut s, unit) = if *value >= 1000.0 {
+            (format_pretty_float(1, self.precision, *value / 1000.0), " GHz")
+        } else {
+            (format_pretty_float(3, 0, *value), " MHz")
+        };
+        if self.display_units {
+            s.push_str(unit);
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryCpuFrequency {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
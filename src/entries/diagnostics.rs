@@ -1,10 +1,11 @@
 //! Perf UI Entries based on Bevy Diagnostics
 
 use bevy::prelude::*;
-use bevy::diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
 use bevy::ecs::system::lifetimeless::SRes;
 use bevy::ecs::system::SystemParam;
 use bevy::math::FloatOrd;
+use bevy::utils::Duration;
 
 #[cfg(feature = "sysinfo")]
 use bevy::diagnostic::SystemInformationDiagnosticsPlugin;
@@ -13,8 +14,65 @@ use crate::prelude::*;
 use crate::entry::*;
 use crate::utils::*;
 
+/// Read either the smoothed or the latest value of a diagnostic.
+///
+/// The smoothed value is stable, while the latest value lets you see spikes.
+/// If the smoothed value is not available yet, falls back to the average of
+/// the recorded history.
+pub(crate) fn read_diagnostic(diagnostic: &Diagnostic, smoothed: bool) -> Option<f64> {
+    if smoothed {
+        diagnostic.smoothed().or_else(|| diagnostic.average())
+    } else {
+        diagnostic.value()
+    }
+}
+
+/// Read the value of a diagnostic, with optional extra [`Smoothing`].
+///
+/// With `Smoothing::None`, this is the same as [`read_diagnostic`].
+/// Otherwise, the latest raw measurements are smoothed using `smoother`,
+/// and `smoothed` is ignored.
+pub(crate) fn read_diagnostic_smoothing(
+    diagnostic: &Diagnostic,
+    smoothed: bool,
+    smoothing: Smoothing,
+    smoother: &Smoother,
+) -> Option<f64> {
+    if smoothing == Smoothing::None {
+        smoother.set_sample_fill(diagnostic.history_len(), diagnostic.get_max_history_length());
+        return read_diagnostic(diagnostic, smoothed);
+    }
+    let measurement = diagnostic.measurement()?;
+    let value = smoother.update(smoothing, measurement.time, measurement.value);
+    if !matches!(smoothing, Smoothing::Window(_)) {
+        smoother.set_sample_fill(diagnostic.history_len(), diagnostic.get_max_history_length());
+    }
+    Some(value)
+}
+
+/// Width of the sample fill (" (NNN/NNN)"), if it is displayed.
+pub(crate) fn sample_fill_width(show_sample_fill: bool) -> usize {
+    if show_sample_fill {
+        10
+    } else {
+        0
+    }
+}
+
+/// Append the sample fill (" (n/max)") to a formatted value, if it should be displayed.
+pub(crate) fn push_sample_fill(s: &mut String, show_sample_fill: bool, smoother: &Smoother) {
+    if !show_sample_fill {
+        return;
+    }
+    if let Some((samples, max)) = smoother.sample_fill() {
+        s.push_str(&format!(" ({}/{})", samples, max));
+    }
+}
+
 /// Perf UI Entry to display Bevy's built-in FPS measurement diagnostic.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryFPS {
     /// Custom label. If empty (default), the default label will be used.
@@ -25,10 +83,30 @@ pub struct PerfUiEntryFPS {
     ///
     /// Default: Red-Yellow-Green gradient between 30-60-120 FPS.
     pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
     /// Highlight the value if FPS is below this threshold.
     ///
     /// Default: `20.0`
     pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::HigherIsBetter`
+    pub goal: MetricGoal,
     /// If displayed using a Bar (or other similar) widget that can
     /// show the value within a range, what should its max value be?
     ///
@@ -36,20 +114,94 @@ pub struct PerfUiEntryFPS {
     ///
     /// Default: `None`
     pub max_value_hint: Option<f32>,
-    /// Should we display the smoothed value or the raw value?
+    /// Should we display the smoothed value or the latest raw value?
     ///
     /// Default: true (smoothed)
     pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
     /// Number of digits to display for the integer (whole number) part.
     ///
     /// Default: `4`
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `0`
-    pub precision: u8,
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(0)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
 }
 
 impl Default for PerfUiEntryFPS {
@@ -57,12 +209,27 @@ impl Default for PerfUiEntryFPS {
         PerfUiEntryFPS {
             label: String::new(),
             color_gradient: ColorGradient::new_preset_ryg(30.0, 60.0, 120.0).unwrap(),
+            color_good: None,
+            color_bad: None,
             threshold_highlight: Some(20.0),
+            goal: MetricGoal::HigherIsBetter,
             max_value_hint: None,
             smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
             digits: 4,
-            precision: 0,
+            precision: Precision::Fixed(0),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            smoother: Smoother::default(),
         }
     }
 }
@@ -71,6 +238,8 @@ impl Default for PerfUiEntryFPS {
 ///
 /// Displays the worst (lowest) value in recent history.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryFPSWorst {
     /// Custom label. If empty (default), the default label will be used.
@@ -81,10 +250,30 @@ pub struct PerfUiEntryFPSWorst {
     ///
     /// Default: Red-Yellow-Green gradient between 30-60-120 FPS.
     pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
     /// Highlight the value if FPS is below this threshold.
     ///
     /// Default: `20.0`
     pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::HigherIsBetter`
+    pub goal: MetricGoal,
     /// If displayed using a Bar (or other similar) widget that can
     /// show the value within a range, what should its max value be?
     ///
@@ -92,16 +281,88 @@ pub struct PerfUiEntryFPSWorst {
     ///
     /// Default: `None`
     pub max_value_hint: Option<f32>,
+    /// Keep displaying the lowest FPS for this long, even after
+    /// it is no longer in the recent history.
+    ///
+    /// After this time passes without a new worst value, the displayed
+    /// value eases back toward the current one. Gives a more useful
+    /// "recent peak" reading, as short spikes stay visible for longer.
+    ///
+    /// If `None`, only the recent history is considered.
+    ///
+    /// Default: `None`
+    pub peak_decay: Option<Duration>,
     /// Number of digits to display for the integer (whole number) part.
     ///
     /// Default: `4`
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `0`
-    pub precision: u8,
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(0)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// Internal state for `peak_decay`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub peak: PeakHold,
 }
 
 impl Default for PerfUiEntryFPSWorst {
@@ -109,11 +370,25 @@ impl Default for PerfUiEntryFPSWorst {
         PerfUiEntryFPSWorst {
             label: String::new(),
             color_gradient: ColorGradient::new_preset_ryg(30.0, 60.0, 120.0).unwrap(),
+            color_good: None,
+            color_bad: None,
             threshold_highlight: Some(20.0),
+            goal: MetricGoal::HigherIsBetter,
             max_value_hint: None,
+            peak_decay: None,
             digits: 4,
-            precision: 0,
+            precision: Precision::Fixed(0),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            peak: PeakHold::default(),
         }
     }
 }
@@ -122,6 +397,8 @@ impl Default for PerfUiEntryFPSWorst {
 ///
 /// Displays the frame time in *milliseconds*.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryFrameTime {
     /// Custom label. If empty (default), the default label will be used.
@@ -136,10 +413,30 @@ pub struct PerfUiEntryFrameTime {
     ///
     /// Default: Green-Yellow-Red gradient between the frametimes equivalent to 120-60-30 FPS.
     pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
     /// Highlight the value if frame time is above this threshold.
     ///
     /// Default: frametime equivalent to 20 FPS
     pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
     /// If displayed using a Bar (or other similar) widget that can
     /// show the value within a range, what should its max value be?
     ///
@@ -147,20 +444,109 @@ pub struct PerfUiEntryFrameTime {
     ///
     /// Default: `None`
     pub max_value_hint: Option<f32>,
-    /// Should we display the smoothed value or the raw value?
+    /// Should we display the smoothed value or the latest raw value?
     ///
     /// Default: true (smoothed)
     pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
+    /// Hold the worst frame time seen, until it is cleared.
+    ///
+    /// Catches rare hitches, that would otherwise be gone before you notice
+    /// them. The value never decays: it is only replaced by an even worse
+    /// frame time. Press the `PerfUiRoot`'s `latch_clear_key` to clear it.
+    /// While a value is held, it is displayed highlighted.
+    ///
+    /// The latest raw value is used, so `smoothed` and `smoothing` are ignored.
+    ///
+    /// Default: `false`
+    pub latch_max: bool,
     /// Number of digits to display for the integer (whole number) part.
     ///
     /// Default: `2`
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `3`
-    pub precision: u8,
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+    /// Internal state for `latch_max`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub latch: LatchedPeak,
 }
 
 impl Default for PerfUiEntryFrameTime {
@@ -173,24 +559,48 @@ impl Default for PerfUiEntryFrameTime {
                 1000.0 / 60.0,
                 1000.0 / 30.0,
             ).unwrap(),
+            color_good: None,
+            color_bad: None,
             threshold_highlight: Some(1000.0 / 20.0),
+            goal: MetricGoal::LowerIsBetter,
             max_value_hint: None,
             smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            latch_max: false,
             digits: 2,
-            precision: 3,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+            latch: LatchedPeak::default(),
         }
     }
 }
 
-/// Perf UI Entry to display Bevy's built-in frame time measurement diagnostic.
+/// Perf UI Entry to display the GPU time spent rendering each frame.
 ///
-/// Displays the worst (highest) value in recent history.
+/// Displays the time in *milliseconds*.
 ///
-/// Displays the frame time in *milliseconds*.
+/// This is the sum of the GPU time of all top-level render passes, as recorded
+/// by Bevy's `RenderDiagnosticsPlugin` (which you must add to your app).
+/// Compare it with [`PerfUiEntryFrameTime`] to tell if you are CPU- or GPU-bound.
+///
+/// GPU timings are only supported on some backends (Vulkan and DX12).
+/// Elsewhere, the value will be unavailable.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
-pub struct PerfUiEntryFrameTimeWorst {
+pub struct PerfUiEntryGpuFrameTime {
     /// Custom label. If empty (default), the default label will be used.
     pub label: String,
     /// Display the unit ("ms") alongside the number.
@@ -203,10 +613,30 @@ pub struct PerfUiEntryFrameTimeWorst {
     ///
     /// Default: Green-Yellow-Red gradient between the frametimes equivalent to 120-60-30 FPS.
     pub color_gradient: ColorGradient,
-    /// Highlight the value if frame time is above this threshold.
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if GPU time is above this threshold.
     ///
     /// Default: frametime equivalent to 20 FPS
     pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
     /// If displayed using a Bar (or other similar) widget that can
     /// show the value within a range, what should its max value be?
     ///
@@ -214,21 +644,82 @@ pub struct PerfUiEntryFrameTimeWorst {
     ///
     /// Default: `None`
     pub max_value_hint: Option<f32>,
+    /// Should we display the smoothed value or the latest raw value?
+    ///
+    /// Default: true (smoothed)
+    pub smoothed: bool,
     /// Number of digits to display for the integer (whole number) part.
     ///
     /// Default: `2`
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `3`
-    pub precision: u8,
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
 
-impl Default for PerfUiEntryFrameTimeWorst {
+impl Default for PerfUiEntryGpuFrameTime {
     fn default() -> Self {
-        PerfUiEntryFrameTimeWorst {
+        PerfUiEntryGpuFrameTime {
             label: String::new(),
             display_units: true,
             color_gradient: ColorGradient::new_preset_gyr(
@@ -236,55 +727,682 @@ impl Default for PerfUiEntryFrameTimeWorst {
                 1000.0 / 60.0,
                 1000.0 / 30.0,
             ).unwrap(),
+            color_good: None,
+            color_bad: None,
             threshold_highlight: Some(1000.0 / 20.0),
+            goal: MetricGoal::LowerIsBetter,
             max_value_hint: None,
+            smoothed: true,
             digits: 2,
-            precision: 3,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
 }
 
-/// Perf UI Entry to display Bevy's built-in frame counter.
+/// Perf UI Entry to display Bevy's built-in frame time measurement diagnostic.
+///
+/// Displays the worst (highest) value in recent history.
+///
+/// Displays the frame time in *milliseconds*.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
-pub struct PerfUiEntryFrameCount {
+pub struct PerfUiEntryFrameTimeWorst {
     /// Custom label. If empty (default), the default label will be used.
     pub label: String,
-    /// Number of digits to display.
+    /// Display the unit ("ms") alongside the number.
     ///
-    /// Default: `6`
+    /// Default: `true`
+    pub display_units: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between the frametimes equivalent to 120-60-30 FPS.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if frame time is above this threshold.
+    ///
+    /// Default: frametime equivalent to 20 FPS
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Keep displaying the highest frame time for this long, even after
+    /// it is no longer in the recent history.
+    ///
+    /// After this time passes without a new worst value, the displayed
+    /// value eases back toward the current one. Gives a more useful
+    /// "recent peak" reading, as short spikes stay visible for longer.
+    ///
+    /// If `None`, only the recent history is considered.
+    ///
+    /// Default: `None`
+    pub peak_decay: Option<Duration>,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `2`
     pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// Internal state for `peak_decay`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub peak: PeakHold,
 }
 
-impl Default for PerfUiEntryFrameCount {
+impl Default for PerfUiEntryFrameTimeWorst {
     fn default() -> Self {
-        PerfUiEntryFrameCount {
+        PerfUiEntryFrameTimeWorst {
             label: String::new(),
-            digits: 6,
+            display_units: true,
+            color_gradient: ColorGradient::new_preset_gyr(
+                1000.0 / 120.0,
+                1000.0 / 60.0,
+                1000.0 / 30.0,
+            ).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(1000.0 / 20.0),
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            peak_decay: None,
+            digits: 2,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            peak: PeakHold::default(),
         }
     }
 }
 
-/// Perf UI Entry to display Bevy's built-in ECS entity counter.
+/// Perf UI Entry to display how much of the frame time budget is being used.
+///
+/// The budget is the frame time corresponding to `target_fps`.
+/// Displays the frame time as a *percentage* of the budget.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
-pub struct PerfUiEntryEntityCount {
+pub struct PerfUiEntryFrameBudget {
     /// Custom label. If empty (default), the default label will be used.
     pub label: String,
+    /// The framerate you are targeting. Determines the frame time budget.
+    ///
+    /// Default: `60.0`
+    pub target_fps: f64,
+    /// Display the unit ("%") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
     /// Enable color based on value.
     ///
     /// To disable (always use default color), set to empty `ColorGradient::default()`.
     ///
-    /// Default: Green-Yellow-Red gradient between 100-1000-10000.
+    /// Default: Green-Yellow-Red gradient between 75%-100%-125%.
     pub color_gradient: ColorGradient,
-    /// Highlight the value if above this threshold.
+    /// Custom color for good values (the good end of `color_gradient`).
     ///
-    /// Default: `20000`
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if above this threshold.
+    ///
+    /// Default: 150%
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Should we use the smoothed frame time or the latest raw value?
+    ///
+    /// Default: true (smoothed)
+    pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `3`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(1)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+}
+
+impl Default for PerfUiEntryFrameBudget {
+    fn default() -> Self {
+        PerfUiEntryFrameBudget {
+            label: String::new(),
+            target_fps: 60.0,
+            display_units: true,
+            color_gradient: ColorGradient::new_preset_gyr(75.0, 100.0, 125.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(150.0),
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            digits: 3,
+            precision: Precision::Fixed(1),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+/// Perf UI Entry to display any Bevy diagnostic, given its path.
+///
+/// Use this to display your own custom diagnostics, or any Bevy
+/// diagnostics that do not have a dedicated entry type.
+///
+/// There is no `Default` impl, because a path is required.
+/// Use [`PerfUiEntryDiagnostic::new`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryDiagnostic {
+    /// The path of the diagnostic to display.
+    #[cfg_attr(feature = "serde", serde(with = "crate::layout::serde_diagnostic_path"))]
+    pub path: DiagnosticPath,
+    /// Custom label. If empty (default), the diagnostic path will be used.
+    pub label: String,
+    /// Units to display alongside the number (for example: `"ms"`).
+    ///
+    /// If empty, no units will be displayed.
+    ///
+    /// Default: empty
+    pub units: String,
+    /// Enable color based on value.
+    ///
+    /// Default: empty `ColorGradient` (always use default color)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is worse than this threshold (see `goal`).
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Should we display the smoothed value or the latest raw value?
+    ///
+    /// Default: true (smoothed)
+    pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the settings above.
+    ///
+    /// Replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+}
+
+impl PerfUiEntryDiagnostic {
+    /// Create a new entry to display the diagnostic with the given path.
+    pub fn new(path: DiagnosticPath) -> Self {
+        PerfUiEntryDiagnostic {
+            path,
+            label: String::new(),
+            units: String::new(),
+            color_gradient: ColorGradient::new(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            digits: 4,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+/// Perf UI Entry to display Bevy's built-in frame counter.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryFrameCount {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Number of digits to display.
+    ///
+    /// Default: `6`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the frame count.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the frame count, before the change
+    /// (like "12345 (+1)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryFrameCount {
+    fn default() -> Self {
+        PerfUiEntryFrameCount {
+            label: String::new(),
+            digits: 6,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+/// Perf UI Entry to display Bevy's built-in ECS entity counter.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryEntityCount {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 100-1000-10000.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if above this threshold.
+    ///
+    /// Default: `20000`
     pub threshold_highlight: Option<u32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
     /// If displayed using a Bar (or other similar) widget that can
     /// show the value within a range, what should its max value be?
     ///
@@ -296,8 +1414,62 @@ pub struct PerfUiEntryEntityCount {
     ///
     /// Default: `6`
     pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the entity count.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the entity count, before the change
+    /// (like "1240 (+12)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
 }
 
 impl Default for PerfUiEntryEntityCount {
@@ -305,10 +1477,22 @@ impl Default for PerfUiEntryEntityCount {
         PerfUiEntryEntityCount {
             label: String::new(),
             color_gradient: ColorGradient::new_preset_gyr(100.0, 1000.0, 10000.0).unwrap(),
+            color_good: None,
+            color_bad: None,
             threshold_highlight: Some(20000),
+            goal: MetricGoal::LowerIsBetter,
             max_value_hint: None,
             digits: 6,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
         }
     }
 }
@@ -318,6 +1502,8 @@ impl Default for PerfUiEntryEntityCount {
 /// Displays the usage as a percentage.
 #[cfg(feature = "sysinfo")]
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryCpuUsage {
     /// Custom label. If empty (default), the default label will be used.
@@ -328,131 +1514,707 @@ pub struct PerfUiEntryCpuUsage {
     ///
     /// Default: Green-Yellow-Red gradient between 25%-50%-75%.
     pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
     /// Highlight the value if above this threshold.
     ///
     /// Default: 90%
     pub threshold_highlight: Option<f32>,
-    /// Should we display the smoothed value or the raw value?
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// Should we display the smoothed value or the latest raw value?
     ///
     /// Default: true (smoothed)
     pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `2`
-    pub precision: u8,
-    /// Sort Key (control where the entry will appear in the Perf UI).
-    pub sort_key: i32,
-}
-
-#[cfg(feature = "sysinfo")]
-impl Default for PerfUiEntryCpuUsage {
-    fn default() -> Self {
-        PerfUiEntryCpuUsage {
-            label: String::new(),
-            color_gradient: ColorGradient::new_preset_gyr(25.0, 50.0, 75.0).unwrap(),
-            threshold_highlight: Some(90.0),
-            smoothed: true,
-            precision: 2,
-            sort_key: next_sort_key(),
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+}
+
+#[cfg(feature = "sysinfo")]
+impl Default for PerfUiEntryCpuUsage {
+    fn default() -> Self {
+        PerfUiEntryCpuUsage {
+            label: String::new(),
+            color_gradient: ColorGradient::new_preset_gyr(25.0, 50.0, 75.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(90.0),
+            goal: MetricGoal::LowerIsBetter,
+            smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+/// Perf UI Entry to display Bevy's built-in Memory (RAM) Usage measurement diagnostic.
+///
+/// Displays the usage as a percentage.
+#[cfg(feature = "sysinfo")]
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryMemUsage {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 25%-50%-75%.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if above this threshold.
+    ///
+    /// Default: 90%
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// Should we display the smoothed value or the latest raw value?
+    ///
+    /// Default: true (smoothed)
+    pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+}
+
+#[cfg(feature = "sysinfo")]
+impl Default for PerfUiEntryMemUsage {
+    fn default() -> Self {
+        PerfUiEntryMemUsage {
+            label: String::new(),
+            color_gradient: ColorGradient::new_preset_gyr(25.0, 50.0, 75.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(90.0),
+            goal: MetricGoal::LowerIsBetter,
+            smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryFPS {
+    type SystemParam = SRes<DiagnosticsStore>;
+    type Value = f64;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "FPS"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        width_hint_pretty_float_precision(self.digits, self.precision) + sample_fill_width(self.show_sample_fill)
+    }
+    fn update_value(
+        &self,
+        diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        read_diagnostic_smoothing(diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)?, self.smoothed, self.smoothing, &self.smoother)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryFPS {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryFPSWorst {
+    type SystemParam = (
+        SRes<DiagnosticsStore>,
+        SRes<Time<Real>>,
+    );
+    type Value = f32;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "FPS (min)"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        width_hint_pretty_float_precision(self.digits, self.precision)
+    }
+    fn update_value(
+        &self,
+        (diagnostics, time): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let worst = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)?
+            .values()
+            .filter_map(|f| if !f.is_nan() {
+                Some(FloatOrd(*f as f32))
+            } else {
+                None
+            })
+            .min()?.0;
+        Some(if let Some(decay) = self.peak_decay {
+            self.peak.update(worst, time.elapsed(), decay, self.goal)
+        } else {
+            worst
+        })
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value as f64)
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryFPSWorst {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        )
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryFrameTime {
+    type SystemParam = SRes<DiagnosticsStore>;
+    type Value = f64;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Frame Time"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision) + sample_fill_width(self.show_sample_fill);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let diagnostic = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?;
+        if self.latch_max {
+            return diagnostic.value()
+                .map(|v| self.latch.update(v, self.goal));
+        }
+        read_diagnostic_smoothing(diagnostic, self.smoothed, self.smoothing, &self.smoother)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        if self.latch_max && self.latch.get().is_some() {
+            return true;
         }
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
     }
 }
 
-/// Perf UI Entry to display Bevy's built-in Memory (RAM) Usage measurement diagnostic.
-///
-/// Displays the usage as a percentage.
-#[cfg(feature = "sysinfo")]
-#[derive(Component, Debug, Clone)]
-#[require(PerfUiRoot)]
-pub struct PerfUiEntryMemUsage {
-    /// Custom label. If empty (default), the default label will be used.
-    pub label: String,
-    /// Enable color based on value.
-    ///
-    /// To disable (always use default color), set to empty `ColorGradient::default()`.
-    ///
-    /// Default: Green-Yellow-Red gradient between 25%-50%-75%.
-    pub color_gradient: ColorGradient,
-    /// Highlight the value if above this threshold.
-    ///
-    /// Default: 90%
-    pub threshold_highlight: Option<f32>,
-    /// Should we display the smoothed value or the raw value?
-    ///
-    /// Default: true (smoothed)
-    pub smoothed: bool,
-    /// Number of digits to display for the fractional (after the decimal point) part.
-    ///
-    /// Default: `2`
-    pub precision: u8,
-    /// Sort Key (control where the entry will appear in the Perf UI).
-    pub sort_key: i32,
-}
-
-#[cfg(feature = "sysinfo")]
-impl Default for PerfUiEntryMemUsage {
-    fn default() -> Self {
-        PerfUiEntryMemUsage {
-            label: String::new(),
-            color_gradient: ColorGradient::new_preset_gyr(25.0, 50.0, 75.0).unwrap(),
-            threshold_highlight: Some(90.0),
-            smoothed: true,
-            precision: 2,
-            sort_key: next_sort_key(),
-        }
+impl PerfUiEntryDisplayRange for PerfUiEntryFrameTime {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
     }
 }
 
-impl PerfUiEntry for PerfUiEntryFPS {
+impl PerfUiEntry for PerfUiEntryGpuFrameTime {
     type SystemParam = SRes<DiagnosticsStore>;
     type Value = f64;
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            "FPS"
+            "GPU Frame Time"
         } else {
             &self.label
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_float(self.digits, self.precision)
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(if self.smoothed {
-            diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)?.smoothed()?
-        } else {
-            diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)?.value()?
-        })
+        let mut total = None;
+        for diagnostic in diagnostics.iter() {
+            let path = diagnostic.path();
+            // only count top-level spans ("render/<pass>/elapsed_gpu"),
+            // because nested spans are already included in their parent
+            let mut components = path.components();
+            if components.next() != Some("render")
+                || components.next().is_none()
+                || components.next() != Some("elapsed_gpu")
+                || components.next().is_some()
+            {
+                continue;
+            }
+            if let Some(value) = read_diagnostic(diagnostic, self.smoothed) {
+                *total.get_or_insert(0.0) += value;
+            }
+        }
+        total
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        format_pretty_float(self.digits, self.precision, *value)
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value as f32)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| (*value as f32) < t)
+            .map(|t| self.goal.is_worse(*value as f32, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
 }
 
-impl PerfUiEntryDisplayRange for PerfUiEntryFPS {
+impl PerfUiEntryDisplayRange for PerfUiEntryGpuFrameTime {
     fn max_value_hint(&self) -> Option<Self::Value> {
         self.max_value_hint.or(
             self.color_gradient.max_stop()
@@ -464,60 +2226,108 @@ impl PerfUiEntryDisplayRange for PerfUiEntryFPS {
     }
 }
 
-impl PerfUiEntry for PerfUiEntryFPSWorst {
-    type SystemParam = SRes<DiagnosticsStore>;
+impl PerfUiEntry for PerfUiEntryFrameTimeWorst {
+    type SystemParam = (
+        SRes<DiagnosticsStore>,
+        SRes<Time<Real>>,
+    );
     type Value = f32;
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            "FPS (min)"
+            "Frame Time (max)"
         } else {
             &self.label
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_float(self.digits, self.precision)
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
     }
     fn update_value(
         &self,
-        diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+        (diagnostics, time): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)?
+        let worst = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?
             .values()
             .filter_map(|f| if !f.is_nan() {
                 Some(FloatOrd(*f as f32))
             } else {
                 None
             })
-            .min()?.0
-        )
+            .max()?.0;
+        Some(if let Some(decay) = self.peak_decay {
+            self.peak.update(worst, time.elapsed(), decay, self.goal)
+        } else {
+            worst
+        })
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        format_pretty_float(self.digits, self.precision, *value as f64)
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value as f64);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| *value < t)
+            .map(|t| self.goal.is_worse(*value, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
 }
 
-impl PerfUiEntryDisplayRange for PerfUiEntryFPSWorst {
+impl PerfUiEntryDisplayRange for PerfUiEntryFrameTimeWorst {
     fn max_value_hint(&self) -> Option<Self::Value> {
         self.max_value_hint.or(
             self.color_gradient.max_stop()
@@ -529,21 +2339,21 @@ impl PerfUiEntryDisplayRange for PerfUiEntryFPSWorst {
     }
 }
 
-impl PerfUiEntry for PerfUiEntryFrameTime {
+impl PerfUiEntry for PerfUiEntryFrameBudget {
     type SystemParam = SRes<DiagnosticsStore>;
     type Value = f64;
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            "Frame Time"
+            "Frame Budget"
         } else {
             &self.label
         }
     }
     fn width_hint(&self) -> usize {
-        let w = width_hint_pretty_float(self.digits, self.precision);
+        let w = width_hint_pretty_float_precision(self.digits, self.precision) + sample_fill_width(self.show_sample_fill);
         if self.display_units {
-            w + 3
+            w + 1
         } else {
             w
         }
@@ -552,42 +2362,76 @@ impl PerfUiEntry for PerfUiEntryFrameTime {
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(if self.smoothed {
-            diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?.smoothed()?
-        } else {
-            diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?.value()?
-        })
+        if self.target_fps <= 0.0 {
+            return None;
+        }
+        let frame_time = read_diagnostic_smoothing(diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?, self.smoothed, self.smoothing, &self.smoother)?;
+        let budget = 1000.0 / self.target_fps;
+        Some(frame_time / budget * 100.0)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        let mut s = format_pretty_float(self.digits, self.precision, *value);
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
         if self.display_units {
-            s.push_str(" ms");
+            s.push('%');
         }
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
         s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value as f32)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| (*value as f32) > t)
+            .map(|t| self.goal.is_worse(*value as f32, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
 }
 
-impl PerfUiEntryDisplayRange for PerfUiEntryFrameTime {
+impl PerfUiEntryDisplayRange for PerfUiEntryFrameBudget {
     fn max_value_hint(&self) -> Option<Self::Value> {
         self.max_value_hint.or(
             self.color_gradient.max_stop()
@@ -599,74 +2443,100 @@ impl PerfUiEntryDisplayRange for PerfUiEntryFrameTime {
     }
 }
 
-impl PerfUiEntry for PerfUiEntryFrameTimeWorst {
+impl PerfUiEntry for PerfUiEntryDiagnostic {
     type SystemParam = SRes<DiagnosticsStore>;
-    type Value = f32;
+    type Value = f64;
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            "Frame Time (max)"
+            self.path.as_str()
         } else {
             &self.label
         }
     }
     fn width_hint(&self) -> usize {
-        let w = width_hint_pretty_float(self.digits, self.precision);
-        if self.display_units {
-            w + 3
-        } else {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision) + sample_fill_width(self.show_sample_fill);
+        if self.units.is_empty() {
             w
+        } else {
+            w + self.units.len() + 1
         }
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)?
-            .values()
-            .filter_map(|f| if !f.is_nan() {
-                Some(FloatOrd(*f as f32))
-            } else {
-                None
-            })
-            .max()?.0
-        )
+        read_diagnostic_smoothing(diagnostics.get(&self.path)?, self.smoothed, self.smoothing, &self.smoother)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        let mut s = format_pretty_float(self.digits, self.precision, *value as f64);
-        if self.display_units {
-            s.push_str(" ms");
+        if let Some(formatter) = &self.formatter {
+            return formatter.format(*value);
         }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if !self.units.is_empty() {
+            s.push(' ');
+            s.push_str(&self.units);
+        }
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
         s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| *value > t)
+            .map(|t| self.goal.is_worse(*value as f32, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
 }
 
-impl PerfUiEntryDisplayRange for PerfUiEntryFrameTimeWorst {
+impl PerfUiEntryDisplayRange for PerfUiEntryDiagnostic {
     fn max_value_hint(&self) -> Option<Self::Value> {
         self.max_value_hint.or(
             self.color_gradient.max_stop()
                 .map(|(v, _)| *v)
-        )
+        ).map(|v| v as f64)
     }
     fn min_value_hint(&self) -> Option<Self::Value> {
         Some(0.0)
@@ -685,19 +2555,48 @@ impl PerfUiEntry for PerfUiEntryFrameCount {
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_int(self.digits)
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_COUNT)?.value()? as u32)
+        let diagnostic = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_COUNT)?;
+        let measurement = diagnostic.measurement()?;
+        if self.show_delta {
+            self.delta.update(measurement.time, measurement.value as i64);
+        }
+        Some(measurement.value as u32)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        format_pretty_int(self.digits, *value as i64)
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
     }
     fn sort_key(&self) -> i32 {
         self.sort_key
@@ -716,34 +2615,71 @@ impl PerfUiEntry for PerfUiEntryEntityCount {
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_int(self.digits)
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(diagnostics.get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)?.value()? as u32)
+        let diagnostic = diagnostics.get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)?;
+        let measurement = diagnostic.measurement()?;
+        if self.show_delta {
+            self.delta.update(measurement.time, measurement.value as i64);
+        }
+        Some(measurement.value as u32)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        format_pretty_int(self.digits, *value as i64)
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value as f32)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| *value > t)
+            .map(|t| self.goal.is_worse(*value, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -774,40 +2710,69 @@ impl PerfUiEntry for PerfUiEntryCpuUsage {
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_float(2, self.precision) + 1
+        width_hint_pretty_float_precision(2, self.precision) + 1 + sample_fill_width(self.show_sample_fill)
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(if self.smoothed {
-            diagnostics.get(&SystemInformationDiagnosticsPlugin::CPU_USAGE)?.smoothed()?
-        } else {
-            diagnostics.get(&SystemInformationDiagnosticsPlugin::CPU_USAGE)?.value()?
-        })
+        read_diagnostic_smoothing(diagnostics.get(&SystemInformationDiagnosticsPlugin::CPU_USAGE)?, self.smoothed, self.smoothing, &self.smoother)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        let mut s = format_pretty_float(2, self.precision, *value);
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(2, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
         s.push('%');
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
         s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value as f32)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| (*value as f32) > t)
+            .map(|t| self.goal.is_worse(*value as f32, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -836,40 +2801,69 @@ impl PerfUiEntry for PerfUiEntryMemUsage {
         }
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_float(2, self.precision) + 1
+        width_hint_pretty_float_precision(2, self.precision) + 1 + sample_fill_width(self.show_sample_fill)
     }
     fn update_value(
         &self,
         diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(if self.smoothed {
-            diagnostics.get(&SystemInformationDiagnosticsPlugin::MEM_USAGE)?.smoothed()?
-        } else {
-            diagnostics.get(&SystemInformationDiagnosticsPlugin::MEM_USAGE)?.value()?
-        })
+        read_diagnostic_smoothing(diagnostics.get(&SystemInformationDiagnosticsPlugin::MEM_USAGE)?, self.smoothed, self.smoothing, &self.smoother)
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        let mut s = format_pretty_float(2, self.precision, *value);
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(2, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
         s.push('%');
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
         s
     }
     fn value_color(
         &self,
         value: &Self::Value,
     ) -> Option<Color> {
-        self.color_gradient.get_color_for_value(*value as f32)
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
     }
     fn value_highlight(
         &self,
         value: &Self::Value,
     ) -> bool {
         self.threshold_highlight
-            .map(|t| (*value as f32) > t)
+            .map(|t| self.goal.is_worse(*value as f32, t))
             .unwrap_or(false)
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -884,3 +2878,200 @@ impl PerfUiEntryDisplayRange for PerfUiEntryMemUsage {
         Some(0.0)
     }
 }
+
+pub(crate) fn clear_perf_ui_latched_frame_time(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    q_root: Query<&PerfUiRoot>,
+    q_entry: Query<&PerfUiEntryFrameTime>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    // entries may be on other entities than their root, so clear them all
+    let pressed = q_root.iter()
+        .any(|root| root.latch_clear_key.is_some_and(|key| keys.just_pressed(key)));
+    if !pressed {
+        return;
+    }
+    for entry in &q_entry {
+        if entry.latch_max {
+            entry.latch.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::diagnostic::DiagnosticMeasurement;
+    use bevy::ecs::system::{RunSystemOnce, SystemState};
+    use bevy::utils::Instant;
+
+    use super::*;
+
+    /// Replace the frame time diagnostic's only measurement.
+    fn measure_frame_time(world: &mut World, ms: f64) {
+        world.resource_mut::<DiagnosticsStore>()
+            .get_mut(&FrameTimeDiagnosticsPlugin::FRAME_TIME).unwrap()
+            .add_measurement(DiagnosticMeasurement { time: Instant::now(), value: ms });
+    }
+
+    #[test]
+    fn frame_time_worst_peak_decays() {
+        let mut world = World::new();
+        let mut diagnostics = DiagnosticsStore::default();
+        diagnostics.add(Diagnostic::new(FrameTimeDiagnosticsPlugin::FRAME_TIME).with_max_history_length(1));
+        world.insert_resource(diagnostics);
+        advance_time(&mut world, Duration::ZERO);
+        let mut state = SystemState::<<PerfUiEntryFrameTimeWorst as PerfUiEntry>::SystemParam>::new(&mut world);
+        let entry = PerfUiEntryFrameTimeWorst {
+            peak_decay: Some(Duration::from_secs(1)),
+            ..default()
+        };
+        let mut update = |world: &mut World, ms: f64, dt_ms: u64| {
+            advance_time(world, Duration::from_millis(dt_ms));
+            measure_frame_time(world, ms);
+            entry.update_value(&mut state.get_mut(world)).unwrap()
+        };
+
+        // a spike is held…
+        assert_eq!(update(&mut world, 50.0, 0), 50.0);
+        assert_eq!(update(&mut world, 10.0, 500), 50.0);
+        assert_eq!(update(&mut world, 10.0, 500), 50.0);
+        // …then relaxes towards the current value, after `peak_decay`
+        let relaxing = update(&mut world, 10.0, 500);
+        assert!(relaxing < 50.0 && relaxing > 10.0, "{relaxing}");
+        assert!(update(&mut world, 10.0, 500) < relaxing);
+        assert!(update(&mut world, 10.0, 10_000) - 10.0 < 0.01);
+        // a new spike is held again
+        assert_eq!(update(&mut world, 30.0, 16), 30.0);
+
+        // without `peak_decay`, only the frame time history counts
+        let entry = PerfUiEntryFrameTimeWorst::default();
+        let mut state = SystemState::<<PerfUiEntryFrameTimeWorst as PerfUiEntry>::SystemParam>::new(&mut world);
+        measure_frame_time(&mut world, 12.0);
+        assert_eq!(entry.update_value(&mut state.get_mut(&mut world)), Some(12.0));
+    }
+
+    #[test]
+    fn instant_and_averaged_frame_time_diverge() {
+        let mut world = World::new();
+        let mut diagnostics = DiagnosticsStore::default();
+        diagnostics.add(Diagnostic::new(FrameTimeDiagnosticsPlugin::FRAME_TIME));
+        world.insert_resource(diagnostics);
+        advance_time(&mut world, Duration::ZERO);
+        let mut state = SystemState::<<PerfUiEntryFrameTime as PerfUiEntry>::SystemParam>::new(&mut world);
+        let instant = PerfUiEntryFrameTime {
+            smoothed: false,
+            ..default()
+        };
+        let smoothed = PerfUiEntryFrameTime::default();
+        let window = PerfUiEntryFrameTime {
+            smoothing: Smoothing::Window(4),
+            ..default()
+        };
+
+        let mut smoothed_range = (f64::INFINITY, f64::NEG_INFINITY);
+        for i in 0..40 {
+            // alternate between short and long frames, measured like Bevy does
+            let dt = if i % 2 == 0 { 10 } else { 30 };
+            advance_time(&mut world, Duration::from_millis(dt));
+            let real = world.resource::<Time<Real>>();
+            let measurement = DiagnosticMeasurement {
+                time: real.startup() + real.elapsed(),
+                value: real.delta().as_secs_f64() * 1000.0,
+            };
+            world.resource_mut::<DiagnosticsStore>()
+                .get_mut(&FrameTimeDiagnosticsPlugin::FRAME_TIME).unwrap()
+                .add_measurement(measurement);
+
+            let mut param = state.get_mut(&mut world);
+            let instant = instant.update_value(&mut param).unwrap();
+            let smoothed = smoothed.update_value(&mut param).unwrap();
+            let window = window.update_value(&mut param).unwrap();
+            // the instant entry follows every frame…
+            assert!((instant - dt as f64).abs() < 1e-9, "{instant}");
+            if i >= 10 {
+                // …while the averaged ones stay in between
+                assert!(smoothed > 12.0 && smoothed < 28.0, "{smoothed}");
+                assert!((window - 20.0).abs() < 1e-9, "{window}");
+                smoothed_range.0 = smoothed_range.0.min(smoothed);
+                smoothed_range.1 = smoothed_range.1.max(smoothed);
+            }
+        }
+        // and the smoothed one moves much less than the raw values
+        assert!(smoothed_range.1 - smoothed_range.0 < 10.0, "{smoothed_range:?}");
+    }
+
+    #[test]
+    fn frame_time_formatting_options() {
+        let entry = PerfUiEntryFrameTime {
+            digits: 3,
+            precision: Precision::Fixed(2),
+            ..default()
+        };
+        assert_eq!(entry.format_value(&16.5), " 16.50 ms");
+        let entry = PerfUiEntryFrameTime {
+            trim_trailing_zeros: true,
+            pad_char: '0',
+            ..entry
+        };
+        assert_eq!(entry.format_value(&16.5), "0016.5 ms");
+        let entry = PerfUiEntryFrameTime {
+            notation: Notation::AutoCompact,
+            precision: Precision::Auto { sig_figs: 3 },
+            ..default()
+        };
+        assert_eq!(entry.format_value(&16.5).trim(), "16.5 ms");
+        // a custom formatter replaces everything, including the units
+        let entry = PerfUiEntryFrameTime {
+            formatter: Some(PerfUiFormatter::new(|ms| format!("{:.0} fps", 1000.0 / ms))),
+            ..default()
+        };
+        assert_eq!(entry.format_value(&20.0), "50 fps");
+    }
+
+    #[test]
+    fn latched_frame_time_is_highlighted_and_cleared() {
+        let mut world = World::new();
+        let mut diagnostics = DiagnosticsStore::default();
+        diagnostics.add(Diagnostic::new(FrameTimeDiagnosticsPlugin::FRAME_TIME));
+        world.insert_resource(diagnostics);
+        let entry = world.spawn((
+            // this entry's own root has no clear key
+            PerfUiRoot {
+                latch_clear_key: None,
+                ..default()
+            },
+            PerfUiEntryFrameTime {
+                latch_max: true,
+                threshold_highlight: None,
+                ..default()
+            },
+        )).id();
+        world.spawn(PerfUiRoot::default());
+        let mut state = SystemState::<<PerfUiEntryFrameTime as PerfUiEntry>::SystemParam>::new(&mut world);
+        let mut update = |world: &mut World, ms: f64| {
+            measure_frame_time(world, ms);
+            let entry = world.get::<PerfUiEntryFrameTime>(entry).unwrap();
+            let value = entry.update_value(&mut state.get(world)).unwrap();
+            (value, entry.value_highlight(&value))
+        };
+
+        // nothing held yet
+        let e = world.get::<PerfUiEntryFrameTime>(entry).unwrap();
+        assert!(!e.value_highlight(&10.0));
+        // the worst value is held, and highlighted, even below any threshold
+        assert_eq!(update(&mut world, 30.0), (30.0, true));
+        assert_eq!(update(&mut world, 10.0), (30.0, true));
+
+        // the other root's key clears it
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::Delete);
+        world.insert_resource(keys);
+        world.run_system_once(clear_perf_ui_latched_frame_time).unwrap();
+        let e = world.get::<PerfUiEntryFrameTime>(entry).unwrap();
+        assert_eq!(e.latch.get(), None);
+        assert!(!e.value_highlight(&10.0));
+        assert_eq!(update(&mut world, 12.0), (12.0, true));
+    }
+}
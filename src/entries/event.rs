@@ -0,0 +1,361 @@
+//! Perf UI Entries for info about Bevy events.
+
+use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock};
+
+use bevy::prelude::*;
+use bevy::core::FrameCount;
+use bevy::ecs::event::Events;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display how many events of type `E` are sent per frame.
+///
+/// Useful to spot "event storms". The events are not read (consumed), so
+/// this does not affect any other `EventReader`s. Instead, the total number
+/// of events ever sent is compared to what it was on the previous update.
+/// That means the count is for the time since the previous update of the
+/// entry: the previous frame, or longer if `throttle` is set.
+///
+/// The value is unavailable if the event type has not been added to the app.
+///
+/// This is generic over the event type, so it must be registered for
+/// each event type you want to use it with:
+///
+/// ```rust
+/// app.add_perf_ui_simple_entry::<PerfUiEntryEventCount<CollisionEvent>>();
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, bound = ""))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryEventCount<E: Event> {
+    /// Custom label. If empty (default), the name of the event type will be used.
+    pub label: String,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: empty (disabled)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<u64>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of events.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of events, before the change
+    /// (like "120 (+12)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for counting events. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub state: EventCountState,
+    /// The event type. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub _event: PhantomData<E>,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+/// The state of a [`PerfUiEntryEventCount`]: the default label, once it
+/// has been computed, and the totals from the previous update.
+///
+/// Cloning gives an empty state, so the count starts over.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct EventCountState {
+    default_label: OnceLock<String>,
+    totals: Mutex<EventCountTotals>,
+}
+
+#[derive(Debug, Default)]
+struct EventCountTotals {
+    /// The total number of events ever sent, on the previous update.
+    total: Option<usize>,
+    /// The frame (`FrameCount`) of the previous update, and its count.
+    last: Option<(u32, u64)>,
+}
+
+impl Clone for EventCountState {
+    fn clone(&self) -> Self {
+        EventCountState::default()
+    }
+}
+
+impl<E: Event> Default for PerfUiEntryEventCount<E> {
+    fn default() -> Self {
+        PerfUiEntryEventCount {
+            label: String::new(),
+            color_gradient: ColorGradient::default(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 4,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            state: EventCountState::default(),
+            _event: PhantomData,
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+/// Strip the module paths from a type name, like "my_game::Foo<my_game::Bar>" -> "Foo<Bar>".
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut s = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    for (i, c) in name.char_indices() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            continue;
+        }
+        s.push_str(name[segment_start..i].rsplit("::").next().unwrap_or_default());
+        s.push(c);
+        segment_start = i + c.len_utf8();
+    }
+    s.push_str(name[segment_start..].rsplit("::").next().unwrap_or_default());
+    s
+}
+
+impl<E: Event> PerfUiEntry for PerfUiEntryEventCount<E> {
+    type Value = u64;
+    type SystemParam = (Option<SRes<Events<E>>>, SRes<FrameCount>);
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            self.state.default_label.get_or_init(|| short_type_name(std::any::type_name::<E>()))
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
+    }
+    fn update_value(
+        &self,
+        (events, frame): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let events = events.as_ref()?;
+        let Ok(mut totals) = self.state.totals.lock() else {
+            return None;
+        };
+        // if updated again in the same frame, the events have already been counted
+        if let Some((last_frame, count)) = totals.last {
+            if last_frame == frame.0 {
+                return Some(count);
+            }
+        }
+        // the two buffers hold consecutive events, so this is
+        // the total number of events ever sent
+        let total = events.oldest_event_count() + events.len();
+        // on the first update, count the events sent since the buffers were last swapped
+        let count = match totals.total {
+            Some(last) => total.saturating_sub(last),
+            None => events.iter_current_update_events().len(),
+        } as u64;
+        totals.total = Some(total);
+        totals.last = Some((frame.0, count));
+        if self.show_delta {
+            self.delta.push(count as i64);
+        }
+        Some(count)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl<E: Event> PerfUiEntryDisplayRange for PerfUiEntryEventCount<E> {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as u64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+
+    #[derive(Event)]
+    struct TestEvent;
+
+    #[test]
+    fn event_count_is_per_frame() {
+        let mut app = crate::utils::test_app();
+        app.add_event::<TestEvent>();
+        app.add_perf_ui_simple_entry::<PerfUiEntryEventCount<TestEvent>>();
+        app.world_mut().spawn(PerfUiEntryEventCount::<TestEvent>::default());
+        app.update();
+
+        app.world_mut().send_event_batch(std::iter::repeat_with(|| TestEvent).take(5));
+        app.update();
+        assert_eq!(app.world().resource::<PerfUiMetrics>().get("TestEvent"), Some(5.0));
+
+        // updating again in the same frame gives the same count
+        let world = app.world_mut();
+        let mut state = SystemState::<<PerfUiEntryEventCount<TestEvent> as PerfUiEntry>::SystemParam>::new(world);
+        let entry = PerfUiEntryEventCount::<TestEvent>::default();
+        entry.update_value(&mut state.get_mut(world));
+        world.send_event_batch(std::iter::repeat_with(|| TestEvent).take(3));
+        world.resource_mut::<FrameCount>().0 += 1;
+        assert_eq!(entry.update_value(&mut state.get_mut(world)), Some(3));
+        assert_eq!(entry.update_value(&mut state.get_mut(world)), Some(3));
+
+        app.update();
+        assert_eq!(app.world().resource::<PerfUiMetrics>().get("TestEvent"), Some(3.0));
+    }
+}
@@ -0,0 +1,499 @@
+//! Perf UI Entries for info about user input.
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::{SQuery, SRes};
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::touch::TouchInput;
+use bevy::utils::{Duration, Instant};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Resource to measure input latency, for [`PerfUiEntryInputLatency`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiInputLatency {
+    pending: Option<Instant>,
+    last: Option<Duration>,
+}
+
+impl PerfUiInputLatency {
+    /// The latency measured for the most recent input.
+    ///
+    /// `None` if there has not been any input yet.
+    pub fn last(&self) -> Option<Duration> {
+        self.last
+    }
+}
+
+/// Perf UI Entry to display an estimate of the input latency.
+///
+/// Bevy input events do not have timestamps, and the time when a frame
+/// is actually presented on the display is not known. Therefore, this is
+/// an approximation: it measures the time from when an input event (keyboard,
+/// mouse button, touch) is first seen by the app, at the start of a frame,
+/// until the end of that frame's update (in the `Last` schedule), when the
+/// effects of the input are ready to be rendered.
+///
+/// It does not include the latency of the OS, rendering, or the display,
+/// so the real input-to-display latency is higher. Still, it is useful
+/// for noticing if your app takes a long time to respond to input.
+///
+/// Displays the latency of the most recent input, in *milliseconds*.
+/// Unavailable until there has been some input.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryInputLatency {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the unit ("ms") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 8ms-16ms-33ms.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `2`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryInputLatency {
+    fn default() -> Self {
+        PerfUiEntryInputLatency {
+            label: String::new(),
+            display_units: true,
+            color_gradient: ColorGradient::new_preset_gyr(8.0, 16.0, 33.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 2,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryInputLatency {
+    type Value = f64;
+    type SystemParam = SRes<PerfUiInputLatency>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Input Latency"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        latency: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        latency.last().map(|d| d.as_secs_f64() * 1000.0)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryInputLatency {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
+
+pub(crate) fn detect_perf_ui_input(
+    mut latency: ResMut<PerfUiInputLatency>,
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut evr_mouse: EventReader<MouseButtonInput>,
+    mut evr_touch: EventReader<TouchInput>,
+) {
+    let n_input = evr_kbd.read().count()
+        + evr_mouse.read().count()
+        + evr_touch.read().count();
+    if n_input > 0 && latency.pending.is_none() {
+        latency.pending = Some(Instant::now());
+    }
+}
+
+pub(crate) fn record_perf_ui_input_latency(
+    mut latency: ResMut<PerfUiInputLatency>,
+) {
+    if let Some(start) = latency.pending.take() {
+        latency.last = Some(start.elapsed());
+    }
+}
+
+/// Perf UI Entry to display the number of connected gamepads.
+///
+/// Optionally, also displays their names, as a second value in the same row.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryGamepadCount {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the names of the connected gamepads, after the count.
+    ///
+    /// Default: `false`
+    pub display_names: bool,
+    /// Truncate the list of names if longer than this many characters, ending it with "…".
+    ///
+    /// Default: `Some(32)`
+    pub max_names_len: Option<usize>,
+    /// Number of digits to display.
+    ///
+    /// Default: `1`
+    pub digits: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryGamepadCount {
+    fn default() -> Self {
+        PerfUiEntryGamepadCount {
+            label: String::new(),
+            display_names: false,
+            max_names_len: Some(32),
+            digits: 1,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryGamepadCount {
+    fn format_names(&self, names: &[String]) -> String {
+        let s = if names.is_empty() {
+            "-".to_owned()
+        } else {
+            names.join(", ")
+        };
+        if let Some(max_len) = self.max_names_len {
+            truncate_with_ellipsis(&s, max_len)
+        } else {
+            s
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryGamepadCount {
+    /// The names of the connected gamepads (sorted)
+    type Value = Vec<String>;
+    type SystemParam = SQuery<Option<&'static Name>, With<Gamepad>>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Gamepads"
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        width_hint_pretty_int(self.digits)
+    }
+    fn width_hint_part(&self, part: usize) -> usize {
+        // the names vary too much to reserve space for them
+        if part == 0 {
+            self.width_hint()
+        } else {
+            0
+        }
+    }
+    fn value_parts(&self) -> usize {
+        if self.display_names {
+            2
+        } else {
+            1
+        }
+    }
+    fn update_value(
+        &self,
+        q_gamepad: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let mut names: Vec<String> = q_gamepad.iter()
+            .map(|name| name.map(|n| n.as_str().to_owned()).unwrap_or_default())
+            .collect();
+        names.sort();
+        Some(names)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_int(self.digits, value.len() as i64);
+        if self.display_names {
+            s.push_str(" (");
+            s.push_str(&self.format_names(value));
+            s.push(')');
+        }
+        s
+    }
+    fn format_value_parts(
+        &self,
+        value: &Self::Value,
+    ) -> Vec<String> {
+        if self.formatter.is_some() {
+            return vec![self.format_value(value)];
+        }
+        let mut parts = vec![format_pretty_int(self.digits, value.len() as i64)];
+        if self.display_names {
+            parts.push(self.format_names(value));
+        }
+        parts
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(value.len() as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
@@ -0,0 +1,959 @@
+//! Miscellaneous Perf UI Entries.
+
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display a fixed piece of text.
+///
+/// Useful for adding information that does not change, such as
+/// the version of your game or the commit hash of the build.
+///
+/// ```rust
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryStatic::new("Version", env!("CARGO_PKG_VERSION")),
+///     PerfUiEntryFPS::default(),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryStatic {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// The text to display as the value.
+    pub text: String,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryStatic {
+    fn default() -> Self {
+        PerfUiEntryStatic {
+            label: String::new(),
+            text: String::new(),
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryStatic {
+    /// Create an entry to display the given text, with the given label.
+    pub fn new(label: impl Into<String>, text: impl Into<String>) -> Self {
+        PerfUiEntryStatic {
+            label: label.into(),
+            text: text.into(),
+            ..default()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryStatic {
+    type Value = ();
+    type SystemParam = ();
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Info"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        self.text.chars().count()
+    }
+    fn update_value(
+        &self,
+        _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        Some(())
+    }
+    fn format_value(
+        &self,
+        _: &Self::Value,
+    ) -> String {
+        self.text.clone()
+    }
+}
+
+/// The version of Bevy that this crate was built for.
+///
+/// Bevy does not expose its version at runtime, so this must be kept
+/// in sync with the Bevy dependency in `Cargo.toml`.
+const BEVY_VERSION: &str = "0.15";
+
+/// Perf UI Entry to display the version of your game and/or of Bevy.
+///
+/// Useful for bug reports: a screenshot of the Perf UI also shows
+/// which build it was taken from.
+///
+/// The game version must be provided by you, because `env!("CARGO_PKG_VERSION")`
+/// must be evaluated in your own crate to give your game's version.
+///
+/// ```rust
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryVersion::new(env!("CARGO_PKG_VERSION")),
+///     PerfUiEntryFPS::default(),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryVersion {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// The version of your game.
+    ///
+    /// Default: empty
+    pub game_version: String,
+    /// Display the game version.
+    ///
+    /// Default: `true`
+    pub display_game_version: bool,
+    /// Display the Bevy version.
+    ///
+    /// Default: `false`
+    pub display_bevy_version: bool,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryVersion {
+    fn default() -> Self {
+        PerfUiEntryVersion {
+            label: String::new(),
+            game_version: String::new(),
+            display_game_version: true,
+            display_bevy_version: false,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryVersion {
+    /// Create an entry to display the given game version.
+    pub fn new(game_version: impl Into<String>) -> Self {
+        PerfUiEntryVersion {
+            game_version: game_version.into(),
+            ..default()
+        }
+    }
+
+    /// Create an entry to display only the Bevy version.
+    pub fn bevy() -> Self {
+        PerfUiEntryVersion {
+            display_game_version: false,
+            display_bevy_version: true,
+            ..default()
+        }
+    }
+
+    /// Also display the Bevy version.
+    pub fn with_bevy_version(mut self) -> Self {
+        self.display_bevy_version = true;
+        self
+    }
+
+    fn text(&self) -> String {
+        let game = self.display_game_version && !self.game_version.is_empty();
+        match (game, self.display_bevy_version) {
+            (true, true) => format!("{} (Bevy {})", self.game_version, BEVY_VERSION),
+            (true, false) => self.game_version.clone(),
+            (false, true) => format!("Bevy {}", BEVY_VERSION),
+            (false, false) => String::new(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryVersion {
+    type Value = ();
+    type SystemParam = ();
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Version"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        self.text().chars().count()
+    }
+    fn update_value(
+        &self,
+        _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        Some(())
+    }
+    fn format_value(
+        &self,
+        _: &Self::Value,
+    ) -> String {
+        self.text()
+    }
+}
+
+/// Perf UI Entry to display a value sent from elsewhere, via a channel.
+///
+/// Useful for displaying metrics that are measured outside of Bevy
+/// systems, such as on a background thread. Send `f64` values into
+/// the channel, and the entry will display the most recent one.
+///
+/// All values received since the last update are drained without
+/// blocking. If the sending side of the channel is dropped, the entry
+/// displays as unavailable.
+///
+/// ```rust
+/// let (entry, sender) = PerfUiEntryChannel::channel("Worker Time");
+/// std::thread::spawn(move || {
+///     loop {
+///         let t = do_some_work();
+///         if sender.send(t).is_err() {
+///             break;
+///         }
+///     }
+/// });
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     entry,
+/// ));
+/// ```
+#[derive(Component, Debug, Clone)]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryChannel {
+    /// Custom label. If empty, the default label will be used.
+    pub label: String,
+    /// Units to display alongside the number (for example: `"ms"`).
+    ///
+    /// If empty, no units will be displayed.
+    ///
+    /// Default: empty
+    pub units: String,
+    /// Enable color based on value.
+    ///
+    /// Default: empty `ColorGradient` (always use default color)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is worse than this threshold (see `goal`).
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the settings above.
+    ///
+    /// Replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    state: Arc<Mutex<ChannelState>>,
+}
+
+#[derive(Debug)]
+struct ChannelState {
+    receiver: Receiver<f64>,
+    latest: Option<f64>,
+    disconnected: bool,
+}
+
+impl PerfUiEntryChannel {
+    /// Create a new entry to display values received from the given channel.
+    pub fn new(label: impl Into<String>, receiver: Receiver<f64>) -> Self {
+        PerfUiEntryChannel {
+            label: label.into(),
+            units: String::new(),
+            color_gradient: ColorGradient::new(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 4,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            state: Arc::new(Mutex::new(ChannelState {
+                receiver,
+                latest: None,
+                disconnected: false,
+            })),
+        }
+    }
+
+    /// Create a new channel, and an entry to display values sent into it.
+    pub fn channel(label: impl Into<String>) -> (Self, Sender<f64>) {
+        let (sender, receiver) = mpsc::channel();
+        (Self::new(label, receiver), sender)
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryChannel {
+    type Value = f64;
+    type SystemParam = ();
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Channel"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.units.is_empty() {
+            w
+        } else {
+            w + self.units.len() + 1
+        }
+    }
+    fn update_value(
+        &self,
+        _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let mut state = self.state.lock().ok()?;
+        loop {
+            match state.receiver.try_recv() {
+                Ok(value) => state.latest = Some(value),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    state.disconnected = true;
+                    break;
+                }
+            }
+        }
+        if state.disconnected {
+            None
+        } else {
+            state.latest
+        }
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let Some(formatter) = &self.formatter {
+            return formatter.format(*value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if !self.units.is_empty() {
+            s.push(' ');
+            s.push_str(&self.units);
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryChannel {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
+
+/// Perf UI Entry to display a value that you set manually.
+///
+/// This is the simplest way to display anything you like, without
+/// implementing your own entry type. Great for prototyping.
+///
+/// Call [`set_value`](Self::set_value) from your systems whenever the
+/// value changes. It only needs shared (`&`) access, so that the
+/// component is not marked as changed (which would cause its UI to be
+/// rebuilt every time):
+///
+/// ```rust
+/// fn update_my_value(
+///     q: Query<&PerfUiEntryManual>,
+/// ) {
+///     for entry in &q {
+///         entry.set_value(42.0);
+///     }
+/// }
+/// ```
+///
+/// Until a value is set, the entry displays as unavailable.
+#[derive(Component, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryManual {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Units to display alongside the number (for example: `"ms"`).
+    ///
+    /// If empty, no units will be displayed.
+    ///
+    /// Default: empty
+    pub units: String,
+    /// Enable color based on value.
+    ///
+    /// Default: empty `ColorGradient` (always use default color)
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is worse than this threshold (see `goal`).
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the settings above.
+    ///
+    /// Replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// The current value. Prefer `set_value` and `clear_value` to change it.
+    ///
+    /// Default: `None` (unavailable)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub value: Mutex<Option<f64>>,
+}
+
+impl Default for PerfUiEntryManual {
+    fn default() -> Self {
+        PerfUiEntryManual {
+            label: String::new(),
+            units: String::new(),
+            color_gradient: ColorGradient::new(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            digits: 4,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            value: Mutex::new(None),
+        }
+    }
+}
+
+impl Clone for PerfUiEntryManual {
+    fn clone(&self) -> Self {
+        PerfUiEntryManual {
+            label: self.label.clone(),
+            units: self.units.clone(),
+            color_gradient: self.color_gradient.clone(),
+            color_good: self.color_good,
+            color_bad: self.color_bad,
+            threshold_highlight: self.threshold_highlight,
+            goal: self.goal,
+            max_value_hint: self.max_value_hint,
+            digits: self.digits,
+            precision: self.precision,
+            notation: self.notation,
+            trim_trailing_zeros: self.trim_trailing_zeros,
+            pad_char: self.pad_char,
+            formatter: self.formatter.clone(),
+            row_background: self.row_background,
+            fixed_color: self.fixed_color,
+            throttle: self.throttle,
+            stale_after: self.stale_after,
+            enabled: self.enabled,
+            sort_key: self.sort_key,
+            value: Mutex::new(self.value()),
+        }
+    }
+}
+
+impl PerfUiEntryManual {
+    /// Create a new entry with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        PerfUiEntryManual {
+            label: label.into(),
+            ..default()
+        }
+    }
+
+    /// Set the value to display.
+    pub fn set_value(&self, value: f64) {
+        if let Ok(mut v) = self.value.lock() {
+            *v = Some(value);
+        }
+    }
+
+    /// Clear the value, so that the entry displays as unavailable.
+    ///
+    /// With `stale_after`, the last value is displayed as stale instead.
+    pub fn clear_value(&self) {
+        if let Ok(mut v) = self.value.lock() {
+            *v = None;
+        }
+    }
+
+    /// Get the most recently set value.
+    pub fn value(&self) -> Option<f64> {
+        self.value.lock().ok().and_then(|v| *v)
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryManual {
+    type Value = f64;
+    type SystemParam = ();
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Value"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.units.is_empty() {
+            w
+        } else {
+            w + self.units.len() + 1
+        }
+    }
+    fn update_value(
+        &self,
+        _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        self.value()
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let Some(formatter) = &self.formatter {
+            return formatter.format(*value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if !self.units.is_empty() {
+            s.push(' ');
+            s.push_str(&self.units);
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryManual {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_set_value() {
+        let entry = PerfUiEntryManual::new("Score");
+        assert_eq!(entry.value(), None);
+        entry.set_value(42.5);
+        assert_eq!(entry.value(), Some(42.5));
+        assert_eq!(entry.update_value(&mut ()), Some(42.5));
+        assert_eq!(entry.clone().value(), Some(42.5));
+        entry.clear_value();
+        assert_eq!(entry.update_value(&mut ()), None);
+
+        // the display follows the value set from game code
+        let mut app = crate::utils::test_app();
+        app.world_mut().spawn(PerfUiEntryManual::new("Score"));
+        for value in [1.0, 2.5] {
+            app.world_mut().query::<&PerfUiEntryManual>().single(app.world()).set_value(value);
+            app.update();
+            let display = app.world().resource::<PerfUiDisplayCache>();
+            assert_eq!(display.get("Score").map(str::trim), Some(format!("{value:.2}").as_str()));
+        }
+    }
+}
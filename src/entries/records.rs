@@ -0,0 +1,438 @@
+//! Perf UI Entries for displaying all-time records (best values).
+//!
+//! [`PerfUiEntryRecord`] displays the best value ever seen for another
+//! entry (like "the highest FPS"). The records are kept in the
+//! [`PerfUiRecords`] resource, based on the values in [`PerfUiMetrics`].
+//!
+//! By default, records only last for the current session. With the `serde`
+//! cargo feature, add [`PerfUiRecordsFilePlugin`] to also save them to
+//! a file, so they persist across sessions.
+
+use std::sync::OnceLock;
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::{Duration, HashMap};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// The lowest and highest values ever seen for an entry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerfUiRecord {
+    /// The lowest value.
+    pub min: f64,
+    /// The highest value.
+    pub max: f64,
+}
+
+impl PerfUiRecord {
+    /// The best value, according to `goal`.
+    pub fn best(&self, goal: MetricGoal) -> f64 {
+        match goal {
+            MetricGoal::HigherIsBetter => self.max,
+            MetricGoal::LowerIsBetter => self.min,
+        }
+    }
+
+    /// The worst value, according to `goal`.
+    pub fn worst(&self, goal: MetricGoal) -> f64 {
+        match goal {
+            MetricGoal::HigherIsBetter => self.min,
+            MetricGoal::LowerIsBetter => self.max,
+        }
+    }
+}
+
+/// Resource with the all-time records, for [`PerfUiEntryRecord`].
+///
+/// Keyed by the label of the entry. Records are only kept for entries
+/// that are used by a `PerfUiEntryRecord`, but records loaded from a file
+/// are kept even if they are not currently displayed.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiRecords {
+    records: HashMap<String, PerfUiRecord>,
+    changed: bool,
+}
+
+impl PerfUiRecords {
+    /// Get the record for the entry with the given label.
+    pub fn get(&self, label: &str) -> Option<PerfUiRecord> {
+        self.records.get(label).copied()
+    }
+
+    /// Iterate over the labels and records of all entries.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, PerfUiRecord)> {
+        self.records.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Update the record for an entry with a new value.
+    ///
+    /// Returns `true` if it is a new record (at either end).
+    pub fn update(&mut self, label: &str, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+        let new_record = if let Some(record) = self.records.get_mut(label) {
+            let new_record = value < record.min || value > record.max;
+            record.min = record.min.min(value);
+            record.max = record.max.max(value);
+            new_record
+        } else {
+            self.records.insert(label.to_owned(), PerfUiRecord {
+                min: value,
+                max: value,
+            });
+            true
+        };
+        self.changed |= new_record;
+        new_record
+    }
+
+    /// Merge records (such as loaded from a file) into these.
+    ///
+    /// The lowest and highest values of both are kept.
+    pub fn merge(&mut self, other: impl IntoIterator<Item = (String, PerfUiRecord)>) {
+        for (label, other) in other {
+            self.update(&label, other.min);
+            self.update(&label, other.max);
+        }
+    }
+
+    /// Forget all records.
+    ///
+    /// If the records are saved to a file, the file will be cleared too.
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.changed = true;
+    }
+}
+
+/// Update the records with the latest values.
+pub(crate) fn update_perf_ui_records(
+    metrics: Res<PerfUiMetrics>,
+    mut records: ResMut<PerfUiRecords>,
+    q_entry: Query<&PerfUiEntryRecord>,
+) {
+    for entry in &q_entry {
+        if let Some(value) = metrics.get(&entry.source) {
+            records.update(&entry.source, value);
+        }
+    }
+}
+
+/// Plugin to save the [`PerfUiRecords`] to a file, so they persist across sessions.
+///
+/// The records are loaded from the file when the plugin is added, saved
+/// periodically when there are new records, and when the app exits.
+/// The file is in RON format.
+///
+/// ```rust
+/// app.add_plugins(PerfUiRecordsFilePlugin::new("perf_records.ron"));
+/// ```
+///
+/// Errors (such as on platforms without a filesystem) are logged as warnings.
+#[cfg(feature = "serde")]
+pub struct PerfUiRecordsFilePlugin {
+    /// The path of the file.
+    pub path: std::path::PathBuf,
+    /// How often to save the records, if there are new ones.
+    ///
+    /// Default: 10 seconds
+    pub save_interval: Duration,
+}
+
+#[cfg(feature = "serde")]
+impl PerfUiRecordsFilePlugin {
+    /// Save the records to the given file.
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        PerfUiRecordsFilePlugin {
+            path: path.into(),
+            save_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Plugin for PerfUiRecordsFilePlugin {
+    fn build(&self, app: &mut App) {
+        let file = PerfUiRecordsFile {
+            path: self.path.clone(),
+            save_interval: self.save_interval,
+            last_save: Duration::ZERO,
+        };
+        let loaded = file.load();
+        app.init_resource::<PerfUiRecords>();
+        let mut records = app.world_mut().resource_mut::<PerfUiRecords>();
+        records.merge(loaded);
+        // no need to save what was just loaded
+        records.changed = false;
+        app.insert_resource(file);
+        app.add_systems(Last, save_perf_ui_records);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Resource)]
+struct PerfUiRecordsFile {
+    path: std::path::PathBuf,
+    save_interval: Duration,
+    /// As per `Time<Real>::elapsed()`.
+    last_save: Duration,
+}
+
+#[cfg(feature = "serde")]
+impl PerfUiRecordsFile {
+    fn load(&self) -> HashMap<String, PerfUiRecord> {
+        let text = match std::fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            // no records yet
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return default(),
+            Err(e) => {
+                warn!("Perf UI: cannot read records from {:?}: {}", self.path, e);
+                return default();
+            }
+        };
+        ron::from_str(&text).unwrap_or_else(|e| {
+            warn!("Perf UI: cannot parse records from {:?}: {}", self.path, e);
+            default()
+        })
+    }
+
+    fn save(&self, records: &PerfUiRecords) {
+        let result = ron::ser::to_string_pretty(&records.records, default())
+            .map_err(|e| e.to_string())
+            .and_then(|text| std::fs::write(&self.path, text).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("Perf UI: cannot save records to {:?}: {}", self.path, e);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn save_perf_ui_records(
+    time: Res<Time<Real>>,
+    mut evr_exit: EventReader<AppExit>,
+    mut file: ResMut<PerfUiRecordsFile>,
+    mut records: ResMut<PerfUiRecords>,
+) {
+    let exiting = evr_exit.read().count() > 0;
+    if !records.changed {
+        return;
+    }
+    let due = time.elapsed().saturating_sub(file.last_save) >= file.save_interval;
+    if due || exiting {
+        file.save(&records);
+        file.last_save = time.elapsed();
+        records.changed = false;
+    }
+}
+
+/// Perf UI Entry to display the all-time best value of another entry.
+///
+/// The other entry is identified by its label, and must provide a numeric
+/// value (see [`PerfUiMetrics`]). It does not have to be displayed in the
+/// same Perf UI, but it must exist somewhere.
+///
+/// ```rust
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryFPS::default(),
+///     PerfUiEntryRecord::new("FPS"),
+/// ));
+/// ```
+///
+/// See the [module docs](self) for how to keep records across sessions.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryRecord {
+    /// Custom label. If empty (default), "Best <source>" will be used.
+    ///
+    /// Must be different from the label of `source`.
+    pub label: String,
+    /// The label of the entry to keep the record of.
+    pub source: String,
+    /// Is a higher or a lower value better?
+    ///
+    /// Decides which record is displayed.
+    ///
+    /// Default: `MetricGoal::HigherIsBetter`
+    pub goal: MetricGoal,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `4`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(0)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal cache of the default label. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub default_label: RecordLabelCache,
+}
+
+/// The default label of a [`PerfUiEntryRecord`], once it has been computed.
+///
+/// Cloning gives an empty cache, so it is computed again.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct RecordLabelCache(OnceLock<String>);
+
+impl Clone for RecordLabelCache {
+    fn clone(&self) -> Self {
+        RecordLabelCache::default()
+    }
+}
+
+impl Default for PerfUiEntryRecord {
+    fn default() -> Self {
+        PerfUiEntryRecord {
+            label: String::new(),
+            source: String::new(),
+            goal: MetricGoal::HigherIsBetter,
+            digits: 4,
+            precision: Precision::Fixed(0),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            default_label: RecordLabelCache::default(),
+        }
+    }
+}
+
+impl PerfUiEntryRecord {
+    /// Create an entry to display the record of the entry with the given label.
+    pub fn new(source: impl Into<String>) -> Self {
+        PerfUiEntryRecord {
+            source: source.into(),
+            ..default()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryRecord {
+    type Value = f64;
+    type SystemParam = SRes<PerfUiRecords>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            self.default_label.0.get_or_init(|| format!("Best {}", self.source))
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        width_hint_pretty_float_precision(self.digits, self.precision)
+    }
+    fn update_value(
+        &self,
+        records: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        records.get(&self.source).map(|record| record.best(self.goal))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
@@ -0,0 +1,248 @@
+//! Perf UI Entries for inspecting any reflected data.
+
+use bevy::prelude::*;
+use bevy::ecs::reflect::ReflectResource;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::reflect::{GetPath, TypeRegistry};
+use bevy::utils::{Duration, HashMap, HashSet};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Resource with the latest values read by reflection, for [`PerfUiEntryReflect`].
+///
+/// The values are read once per frame (before the Perf UI is updated),
+/// for all the paths used by any `PerfUiEntryReflect`.
+#[derive(Resource, Debug, Default)]
+pub struct PerfUiReflectValues {
+    values: HashMap<(String, String), String>,
+    warned: HashSet<(String, String)>,
+}
+
+impl PerfUiReflectValues {
+    /// Get the latest value of a field of a resource, as displayed by the Perf UI.
+    ///
+    /// `None` if it could not be read, or if no entry is using it.
+    pub fn get(&self, type_path: &str, field_path: &str) -> Option<&str> {
+        self.values.get(&(type_path.to_owned(), field_path.to_owned()))
+            .map(String::as_str)
+    }
+}
+
+/// Perf UI Entry to display any field of a resource, using Bevy Reflection.
+///
+/// The resource type must be registered in the app's type registry,
+/// and reflect `Resource`:
+///
+/// ```rust
+/// #[derive(Resource, Reflect)]
+/// #[reflect(Resource)]
+/// struct MyConfig {
+///     difficulty: u32,
+/// }
+///
+/// app.register_type::<MyConfig>();
+///
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryReflect::new("MyConfig", "difficulty"),
+/// ));
+/// ```
+///
+/// The value is displayed using its `Debug` representation (strings are
+/// displayed as-is). If the type is not registered, the resource does not
+/// exist, or the path is invalid, the value is displayed as unavailable,
+/// and a warning is logged (once for every path).
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryReflect {
+    /// Custom label. If empty (default), the field path will be used.
+    pub label: String,
+    /// The type path of the resource.
+    ///
+    /// Either the full path (like `"my_game::config::MyConfig"`), or the
+    /// short one (like `"MyConfig"`), if it is not ambiguous.
+    pub type_path: String,
+    /// The path of the field to display, within the resource.
+    ///
+    /// Uses Bevy's reflection path syntax (like `"difficulty"`, `"player.health"`,
+    /// or `"levels[2].name"`). If empty, the whole resource is displayed.
+    pub field_path: String,
+    /// Truncate values longer than this many characters, ending them with "…".
+    ///
+    /// Default: `Some(32)`
+    pub max_len: Option<usize>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryReflect {
+    fn default() -> Self {
+        PerfUiEntryReflect {
+            label: String::new(),
+            type_path: String::new(),
+            field_path: String::new(),
+            max_len: Some(32),
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryReflect {
+    /// Create an entry to display the given field of the given resource type.
+    pub fn new(type_path: impl Into<String>, field_path: impl Into<String>) -> Self {
+        PerfUiEntryReflect {
+            type_path: type_path.into(),
+            field_path: field_path.into(),
+            ..default()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryReflect {
+    type Value = String;
+    type SystemParam = SRes<PerfUiReflectValues>;
+
+    fn label(&self) -> &str {
+        if !self.label.is_empty() {
+            &self.label
+        } else if !self.field_path.is_empty() {
+            &self.field_path
+        } else {
+            &self.type_path
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        0
+    }
+    fn update_value(
+        &self,
+        values: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        values.get(&self.type_path, &self.field_path).map(str::to_owned)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let Some(max_len) = self.max_len {
+            truncate_with_ellipsis(value, max_len)
+        } else {
+            value.clone()
+        }
+    }
+}
+
+/// Read the values for all `PerfUiEntryReflect`s.
+pub(crate) fn read_perf_ui_reflect_values(
+    world: &mut World,
+    q_entries: &mut QueryState<&PerfUiEntryReflect>,
+) {
+    let paths: HashSet<(String, String)> = q_entries.iter(world)
+        .map(|entry| (entry.type_path.clone(), entry.field_path.clone()))
+        .collect();
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+    let mut values = HashMap::default();
+    let mut errors = Vec::new();
+    for path in paths {
+        match read_reflect_value(world, &registry, &path.0, &path.1) {
+            Ok(value) => {
+                values.insert(path, value);
+            }
+            Err(e) => {
+                errors.push((path, e));
+            }
+        }
+    }
+    let mut cache = world.resource_mut::<PerfUiReflectValues>();
+    for (path, e) in errors {
+        if cache.warned.insert(path.clone()) {
+            warn!("Perf UI: cannot read {:?} of resource {:?}: {}", path.1, path.0, e);
+        }
+    }
+    cache.values = values;
+}
+
+fn read_reflect_value(
+    world: &World,
+    registry: &TypeRegistry,
+    type_path: &str,
+    field_path: &str,
+) -> Result<String, String> {
+    let registration = registry.get_with_type_path(type_path)
+        .or_else(|| registry.get_with_short_type_path(type_path))
+        .ok_or("the type is not registered (or its short path is ambiguous)")?;
+    let reflect_resource = registration.data::<ReflectResource>()
+        .ok_or("the type does not reflect Resource")?;
+    let resource = reflect_resource.reflect(world)
+        .ok_or("the resource does not exist")?;
+    let value = if field_path.is_empty() {
+        resource.as_partial_reflect()
+    } else {
+        resource.reflect_path(field_path).map_err(|e| e.to_string())?
+    };
+    if let Some(s) = value.try_downcast_ref::<String>() {
+        Ok(s.clone())
+    } else {
+        Ok(format!("{:?}", value))
+    }
+}
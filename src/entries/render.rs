@@ -0,0 +1,169 @@
+//! Perf UI Entries for info about the renderer.
+
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::render::renderer::RenderAdapterInfo;
+use bevy::utils::Duration;
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// Perf UI Entry to display the active render backend and GPU.
+///
+/// Displays something like "Vulkan / NVIDIA GeForce RTX 4070".
+/// Useful to include in screenshots for bug reports.
+///
+/// The renderer does not change while the app is running, so the text
+/// is only computed once. If the render info is not available (such as
+/// in a headless app), the value is unavailable.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryRenderBackend {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the name of the GPU (adapter), after the backend.
+    ///
+    /// Default: `true`
+    pub display_adapter: bool,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal cache of the text. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cache: RenderBackendCache,
+}
+
+/// The text of a [`PerfUiEntryRenderBackend`], once it has been computed,
+/// with the `display_adapter` setting it was computed for.
+///
+/// Cloning gives an empty cache, so it is computed again.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct RenderBackendCache(Mutex<Option<(bool, String)>>);
+
+impl Clone for RenderBackendCache {
+    fn clone(&self) -> Self {
+        RenderBackendCache::default()
+    }
+}
+
+impl Default for PerfUiEntryRenderBackend {
+    fn default() -> Self {
+        PerfUiEntryRenderBackend {
+            label: String::new(),
+            display_adapter: true,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            cache: RenderBackendCache::default(),
+        }
+    }
+}
+
+impl PerfUiEntryRenderBackend {
+    fn text(&self, info: &RenderAdapterInfo) -> String {
+        let backend = match info.backend.to_str() {
+            "vulkan" => "Vulkan",
+            "metal" => "Metal",
+            "dx12" => "DX12",
+            "gl" => "OpenGL",
+            "webgpu" => "WebGPU",
+            other => other,
+        };
+        if self.display_adapter {
+            format!("{} / {}", backend, info.name)
+        } else {
+            backend.to_owned()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryRenderBackend {
+    type Value = String;
+    type SystemParam = Option<SRes<RenderAdapterInfo>>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Renderer"
+        } else {
+            &self.label
+        }
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        0
+    }
+    fn update_value(
+        &self,
+        info: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let Ok(mut cache) = self.cache.0.lock() else {
+            return info.as_ref().map(|info| self.text(info));
+        };
+        if cache.as_ref().is_none_or(|(display_adapter, _)| *display_adapter != self.display_adapter) {
+            *cache = info.as_ref().map(|info| (self.display_adapter, self.text(info)));
+        }
+        cache.as_ref().map(|(_, text)| text.clone())
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        value.clone()
+    }
+}
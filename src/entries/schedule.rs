@@ -0,0 +1,402 @@
+//! Perf UI Entries for displaying how long Bevy schedules take to run.
+
+use bevy::prelude::*;
+use bevy::app::{FixedMainScheduleOrder, MainScheduleOrder};
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::{Duration, HashMap, Instant};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+use super::diagnostics::{push_sample_fill, read_diagnostic_smoothing, sample_fill_width};
+
+/// Plugin to measure how long a schedule takes to run, for [`PerfUiEntryScheduleTime`].
+///
+/// Bevy does not measure the time taken by whole schedules, so you need
+/// to add this plugin for every schedule you want to display. The total
+/// time spent in the schedule during each frame is recorded as a Bevy
+/// diagnostic (in milliseconds).
+///
+/// Only schedules that are run as part of Bevy's `Main` or `FixedMain`
+/// schedules (such as `Update` or `FixedUpdate`) can be measured.
+/// For `FixedUpdate` (and friends), which may run several times per frame,
+/// the time of all runs during the frame is added up.
+///
+/// ```rust
+/// app.add_plugins((
+///     PerfUiScheduleTimingPlugin::new(Update),
+///     PerfUiScheduleTimingPlugin::new(FixedUpdate),
+/// ));
+/// ```
+pub struct PerfUiScheduleTimingPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl PerfUiScheduleTimingPlugin {
+    /// Create a plugin to measure the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        PerfUiScheduleTimingPlugin {
+            schedule: schedule.intern(),
+        }
+    }
+
+    /// The path of the diagnostic where the time of the given schedule is recorded.
+    pub fn diagnostic_path(schedule: impl ScheduleLabel) -> DiagnosticPath {
+        DiagnosticPath::new(format!("schedule/{:?}", schedule.intern()))
+    }
+}
+
+impl Plugin for PerfUiScheduleTimingPlugin {
+    fn build(&self, app: &mut App) {
+        let schedule = self.schedule;
+        let path = Self::diagnostic_path(schedule);
+
+        let in_main = app.world().get_resource::<MainScheduleOrder>()
+            .is_some_and(|order| order.labels.contains(&schedule));
+        let in_fixed_main = app.world().get_resource::<FixedMainScheduleOrder>()
+            .is_some_and(|order| order.labels.contains(&schedule));
+        if in_main {
+            let mut order = app.world_mut().resource_mut::<MainScheduleOrder>();
+            order.insert_before(schedule, ScheduleTimingStart(schedule));
+            order.insert_after(schedule, ScheduleTimingEnd(schedule));
+        } else if in_fixed_main {
+            let mut order = app.world_mut().resource_mut::<FixedMainScheduleOrder>();
+            order.insert_before(schedule, ScheduleTimingStart(schedule));
+            order.insert_after(schedule, ScheduleTimingEnd(schedule));
+        } else {
+            warn!("Perf UI: cannot measure the {:?} schedule, because it is not part of the Main or FixedMain schedules.", schedule);
+            return;
+        }
+
+        if !app.world().contains_resource::<PerfUiScheduleTimings>() {
+            app.init_resource::<PerfUiScheduleTimings>();
+            app.add_systems(First, record_perf_ui_schedule_timings);
+        }
+        app.world_mut().resource_mut::<PerfUiScheduleTimings>()
+            .schedules.insert(schedule, ScheduleTiming {
+                path: path.clone(),
+                started: None,
+                total: Duration::ZERO,
+            });
+        app.register_diagnostic(Diagnostic::new(path).with_suffix("ms"));
+        app.add_systems(ScheduleTimingStart(schedule), move |mut timings: ResMut<PerfUiScheduleTimings>| {
+            if let Some(timing) = timings.schedules.get_mut(&schedule) {
+                timing.started = Some(Instant::now());
+            }
+        });
+        app.add_systems(ScheduleTimingEnd(schedule), move |mut timings: ResMut<PerfUiScheduleTimings>| {
+            if let Some(timing) = timings.schedules.get_mut(&schedule) {
+                if let Some(started) = timing.started.take() {
+                    timing.total += started.elapsed();
+                }
+            }
+        });
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct ScheduleTimingStart(InternedScheduleLabel);
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct ScheduleTimingEnd(InternedScheduleLabel);
+
+#[derive(Resource, Default)]
+struct PerfUiScheduleTimings {
+    schedules: HashMap<InternedScheduleLabel, ScheduleTiming>,
+}
+
+struct ScheduleTiming {
+    path: DiagnosticPath,
+    started: Option<Instant>,
+    total: Duration,
+}
+
+/// Record the total time of each measured schedule during the previous frame.
+fn record_perf_ui_schedule_timings(
+    mut timings: ResMut<PerfUiScheduleTimings>,
+    mut diagnostics: Diagnostics,
+) {
+    for timing in timings.schedules.values_mut() {
+        let total = std::mem::take(&mut timing.total);
+        diagnostics.add_measurement(&timing.path, || total.as_secs_f64() * 1000.0);
+    }
+}
+
+/// Perf UI Entry to display the time taken by a Bevy schedule.
+///
+/// Displays the time in *milliseconds*.
+///
+/// Requires [`PerfUiScheduleTimingPlugin`] to be added for the same
+/// schedule. Otherwise, the value will be unavailable.
+#[derive(Component, Debug, Clone)]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryScheduleTime {
+    /// Custom label. If empty, the default label will be used.
+    ///
+    /// Default: the name of the schedule
+    pub label: String,
+    /// Display the unit ("ms") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 2ms-8ms-16ms.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Should we display the smoothed value or the latest raw value?
+    ///
+    /// Default: true (smoothed)
+    pub smoothed: bool,
+    /// Extra smoothing to apply to the raw values.
+    ///
+    /// If not `Smoothing::None`, this is used instead of `smoothed`.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Display how many samples the value was computed from, out of how many
+    /// it can hold (like "144 (512/1000)").
+    ///
+    /// Useful to know if an average has stabilized, after startup or a reset.
+    ///
+    /// Default: `false`
+    pub show_sample_fill: bool,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `2`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    path: DiagnosticPath,
+    smoother: Smoother,
+}
+
+impl PerfUiEntryScheduleTime {
+    /// Create a new entry to display the time taken by the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        let schedule = schedule.intern();
+        PerfUiEntryScheduleTime {
+            label: format!("{:?}", schedule),
+            display_units: true,
+            color_gradient: ColorGradient::new_preset_gyr(2.0, 8.0, 16.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            smoothed: true,
+            smoothing: Smoothing::None,
+            show_sample_fill: false,
+            digits: 2,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            path: PerfUiScheduleTimingPlugin::diagnostic_path(schedule),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryScheduleTime {
+    type SystemParam = SRes<DiagnosticsStore>;
+    type Value = f64;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            self.path.as_str()
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision) + sample_fill_width(self.show_sample_fill);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        diagnostics: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        read_diagnostic_smoothing(diagnostics.get(&self.path)?, self.smoothed, self.smoothing, &self.smoother)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        push_sample_fill(&mut s, self.show_sample_fill, &self.smoother);
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryScheduleTime {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
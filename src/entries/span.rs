@@ -0,0 +1,396 @@
+//! Perf UI Entries for timing regions of your own code.
+
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::SystemParam;
+use bevy::utils::{Duration, HashMap, Instant};
+
+use crate::prelude::*;
+use crate::entry::*;
+use crate::utils::*;
+
+/// The durations recorded since the last frame, per span name.
+///
+/// There are usually only a few spans, so a `Vec` is faster than a map.
+static SPANS: Mutex<Vec<(String, Duration)>> = Mutex::new(Vec::new());
+
+/// Time a block of code, for [`PerfUiEntrySpan`].
+///
+/// The duration of the block is added to the span with the given name.
+/// Evaluates to the value of the block.
+///
+/// ```rust
+/// fn my_system() {
+///     let contacts = perf_ui_span!("physics", {
+///         step_physics()
+///     });
+/// }
+/// ```
+///
+/// Can be used from any thread. If the block runs more than once per
+/// frame, the durations are added up.
+#[macro_export]
+macro_rules! perf_ui_span {
+    ($name:expr, $body:block) => {{
+        let _guard = $crate::entries::span::PerfUiSpanGuard::new($name);
+        $body
+    }};
+}
+
+/// Add a duration to the span with the given name, for [`PerfUiEntrySpan`].
+///
+/// Use this if you measured the time yourself. Otherwise, the
+/// [`perf_ui_span!`](crate::perf_ui_span) macro is more convenient.
+pub fn perf_ui_record_span(name: &str, duration: Duration) {
+    let Ok(mut spans) = SPANS.lock() else {
+        return;
+    };
+    if let Some((_, total)) = spans.iter_mut().find(|(n, _)| n == name) {
+        *total += duration;
+    } else {
+        spans.push((name.to_owned(), duration));
+    }
+}
+
+/// Measures the time until it is dropped, for [`PerfUiEntrySpan`].
+///
+/// Used by the [`perf_ui_span!`](crate::perf_ui_span) macro. Useful if
+/// the code you want to time is not a single block.
+pub struct PerfUiSpanGuard<'a> {
+    name: &'a str,
+    start: Instant,
+}
+
+impl<'a> PerfUiSpanGuard<'a> {
+    /// Start timing the span with the given name.
+    pub fn new(name: &'a str) -> Self {
+        PerfUiSpanGuard {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for PerfUiSpanGuard<'_> {
+    fn drop(&mut self) {
+        perf_ui_record_span(self.name, self.start.elapsed());
+    }
+}
+
+/// Resource with the time spent in each span, for [`PerfUiEntrySpan`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiSpanTimer {
+    spans: HashMap<String, Duration>,
+    updated: Option<Instant>,
+}
+
+impl PerfUiSpanTimer {
+    /// The total time spent in the span during the previous frame.
+    ///
+    /// `None` if the span was never recorded. If it was recorded before,
+    /// but not during the previous frame, this is zero.
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.spans.get(name).copied()
+    }
+
+    /// Iterate over the names and durations of all spans.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Duration)> {
+        self.spans.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+}
+
+/// Collect the durations recorded during the previous frame.
+pub(crate) fn record_perf_ui_spans(
+    mut timer: ResMut<PerfUiSpanTimer>,
+) {
+    let recorded = SPANS.lock()
+        .map(|mut spans| std::mem::take(&mut *spans))
+        .unwrap_or_default();
+    for total in timer.spans.values_mut() {
+        *total = Duration::ZERO;
+    }
+    for (name, total) in recorded {
+        timer.spans.insert(name, total);
+    }
+    timer.updated = Some(Instant::now());
+}
+
+/// Perf UI Entry to display the time spent in a region of your code.
+///
+/// Time your code using the [`perf_ui_span!`](crate::perf_ui_span) macro,
+/// with the same name as this entry's `name`. The value is unavailable
+/// until the span has been recorded at least once.
+///
+/// Displays the total time during the previous frame, in *milliseconds*.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntrySpan {
+    /// Custom label. If empty (default), the name of the span will be used.
+    pub label: String,
+    /// The name of the span to display.
+    pub name: String,
+    /// Display the unit ("ms") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 1ms-4ms-8ms.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if it is above this threshold.
+    ///
+    /// Default: `None`
+    pub threshold_highlight: Option<f32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// If displayed using a Bar (or other similar) widget that can
+    /// show the value within a range, what should its max value be?
+    ///
+    /// If `None`, the value will be computed from the color gradient.
+    ///
+    /// Default: `None`
+    pub max_value_hint: Option<f32>,
+    /// Smoothing to apply to the values.
+    ///
+    /// With `Smoothing::None`, the time of the previous frame is displayed.
+    ///
+    /// Default: `Smoothing::None`
+    pub smoothing: Smoothing,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `2`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(3)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `smoothing`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub smoother: Smoother,
+}
+
+impl Default for PerfUiEntrySpan {
+    fn default() -> Self {
+        PerfUiEntrySpan {
+            label: String::new(),
+            name: String::new(),
+            display_units: true,
+            color_gradient: ColorGradient::new_preset_gyr(1.0, 4.0, 8.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: None,
+            goal: MetricGoal::LowerIsBetter,
+            max_value_hint: None,
+            smoothing: Smoothing::None,
+            digits: 2,
+            precision: Precision::Fixed(3),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            smoother: Smoother::default(),
+        }
+    }
+}
+
+impl PerfUiEntrySpan {
+    /// Create a new entry to display the span with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        PerfUiEntrySpan {
+            name: name.into(),
+            ..default()
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntrySpan {
+    type SystemParam = SRes<PerfUiSpanTimer>;
+    type Value = f64;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            &self.name
+        } else {
+            &self.label
+        }
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        timer: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let ms = timer.get(&self.name)?.as_secs_f64() * 1000.0;
+        if self.smoothing == Smoothing::None {
+            return Some(ms);
+        }
+        Some(self.smoother.update(self.smoothing, timer.updated?, ms))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" ms");
+        }
+        s
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value as f32, t))
+            .unwrap_or(false)
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntrySpan {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.max_value_hint.or(
+            self.color_gradient.max_stop()
+                .map(|(v, _)| *v)
+        ).map(|v| v as f64)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0.0)
+    }
+}
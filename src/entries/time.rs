@@ -1,23 +1,163 @@
 //! Perf UI Entries for displaying the current time.
 
+use std::marker::PhantomData;
+
 use bevy::prelude::*;
 use bevy::ecs::system::lifetimeless::SRes;
 use bevy::ecs::system::SystemParam;
-use bevy::utils::Duration;
+use bevy::utils::{Duration, HashMap};
 
 use crate::prelude::*;
 use crate::entry::*;
 use crate::utils::*;
 
+/// Which of Bevy's clocks should be used to measure time?
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeSource {
+    /// `Time<Real>`: the actual wall-clock time elapsed.
+    ///
+    /// Unaffected by pausing or changing the speed of the game.
+    #[default]
+    Real,
+    /// `Time<Virtual>`: the game time.
+    ///
+    /// Stops advancing while paused and respects the relative speed setting.
+    Virtual,
+    /// `Time<Fixed>`: the fixed timestep time.
+    ///
+    /// Advances in whole timesteps, as `FixedUpdate` runs.
+    /// It follows virtual time, so it also respects pausing and relative speed.
+    Fixed,
+}
+
+/// Which unit to display a time duration in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    /// Seconds ("s")
+    #[default]
+    Seconds,
+    /// Milliseconds ("ms")
+    Millis,
+    /// Microseconds ("µs")
+    Micros,
+}
+
+impl TimeUnit {
+    /// Convert a duration into a number in this unit.
+    pub fn scale(self, value: Duration) -> f64 {
+        match self {
+            TimeUnit::Seconds => value.as_secs_f64(),
+            TimeUnit::Millis => value.as_secs_f64() * 1000.0,
+            TimeUnit::Micros => value.as_secs_f64() * 1000000.0,
+        }
+    }
+
+    /// The units suffix string to display.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            TimeUnit::Seconds => "s",
+            TimeUnit::Millis => "ms",
+            TimeUnit::Micros => "µs",
+        }
+    }
+}
+
+/// The time context (Bevy clock) of a [`PerfUiEntryRunningTimeGeneric`].
+///
+/// Implemented for Bevy's `Real`, `Virtual` and `Fixed` time contexts,
+/// which use the respective `Time<T>`, and for `()`, which picks the
+/// clock at runtime, based on the entry's `time_source`.
+pub trait PerfUiTimeContext: Default + Send + Sync + 'static {
+    /// The system param to read the clock.
+    type SystemParam: SystemParam + 'static;
+
+    /// The time elapsed since app startup.
+    fn elapsed(
+        time_source: TimeSource,
+        param: &<Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Duration;
+}
+
+impl PerfUiTimeContext for () {
+    type SystemParam = (
+        SRes<Time<Real>>,
+        SRes<Time<Virtual>>,
+        SRes<Time<Fixed>>,
+    );
+
+    fn elapsed(
+        time_source: TimeSource,
+        (time_real, time_virtual, time_fixed): &<Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Duration {
+        match time_source {
+            TimeSource::Real => time_real.elapsed(),
+            TimeSource::Virtual => time_virtual.elapsed(),
+            TimeSource::Fixed => time_fixed.elapsed(),
+        }
+    }
+}
+
+macro_rules! impl_perf_ui_time_context {
+    ($($t:ty),*) => {
+        $(
+            impl PerfUiTimeContext for $t {
+                type SystemParam = SRes<Time<$t>>;
+
+                fn elapsed(
+                    _: TimeSource,
+                    time: &<Self::SystemParam as SystemParam>::Item<'_, '_>,
+                ) -> Duration {
+                    time.elapsed()
+                }
+            }
+        )*
+    };
+}
+
+impl_perf_ui_time_context!(Real, Virtual, Fixed);
+
 /// Perf UI Entry to display the time the Bevy app has been running.
+///
+/// With the default time context (`()`), the clock is selected at
+/// runtime using `time_source`.
+pub type PerfUiEntryRunningTime = PerfUiEntryRunningTimeGeneric<()>;
+
+/// Perf UI Entry to display the time the Bevy app has been running, as per `Time<Real>`.
+pub type PerfUiEntryRunningTimeReal = PerfUiEntryRunningTimeGeneric<Real>;
+
+/// Perf UI Entry to display the time the Bevy app has been running, as per `Time<Virtual>`.
+pub type PerfUiEntryRunningTimeVirtual = PerfUiEntryRunningTimeGeneric<Virtual>;
+
+/// Perf UI Entry to display the time the Bevy app has been running, as per `Time<Fixed>`.
+pub type PerfUiEntryRunningTimeFixed = PerfUiEntryRunningTimeGeneric<Fixed>;
+
+/// Perf UI Entry to display the time the Bevy app has been running.
+///
+/// Generic over the time context, which selects Bevy's clock at compile
+/// time. Use one of the type aliases: [`PerfUiEntryRunningTime`],
+/// [`PerfUiEntryRunningTimeReal`], [`PerfUiEntryRunningTimeVirtual`],
+/// [`PerfUiEntryRunningTimeFixed`].
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, bound = ""))]
 #[require(PerfUiRoot)]
-pub struct PerfUiEntryRunningTime {
+pub struct PerfUiEntryRunningTimeGeneric<T: PerfUiTimeContext = ()> {
     /// Custom label. If empty (default), the default label will be used.
     pub label: String,
+    /// Which clock to use for measuring time.
+    ///
+    /// Only used with the default time context (`()`, as in
+    /// [`PerfUiEntryRunningTime`]). Otherwise, the clock of the time
+    /// context is always used.
+    ///
+    /// Default: [`TimeSource::Real`]
+    pub time_source: TimeSource,
     /// If set, count time relative to this.
     /// If unset, count time since app startup.
-    /// (represented as a duration since startup, as per Bevy's `Time::elapsed()`)
+    /// (represented as a duration since startup, as per Bevy's `Time::elapsed()`
+    /// of the clock of the time context)
     ///
     /// Default: `None`
     pub start: Option<Duration>,
@@ -26,7 +166,23 @@ pub struct PerfUiEntryRunningTime {
     ///
     /// Default: `false`
     pub format_hms: bool,
-    /// Display the unit ("s") alongside the number.
+    /// If true, pick the most readable unit for the current value:
+    /// milliseconds below 1 second, seconds below 1 minute, and
+    /// MM:SS or HH:MM:SS (without fractional part) above that.
+    ///
+    /// `precision` applies to the seconds. Takes priority over `format_hms`.
+    ///
+    /// Default: `false`
+    pub auto_units: bool,
+    /// Which unit to display the time in.
+    ///
+    /// `digits` and `precision` apply to the value in this unit.
+    ///
+    /// Only used if `format_hms = false`.
+    ///
+    /// Default: [`TimeUnit::Seconds`]
+    pub unit_scale: TimeUnit,
+    /// Display the unit ("s", "ms", "µs") alongside the number.
     ///
     /// Only used if `format_hms = false`.
     ///
@@ -42,20 +198,617 @@ pub struct PerfUiEntryRunningTime {
     ///
     /// Default: `3`
     pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
+    /// The time context. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub _time: PhantomData<T>,
 }
 
-impl Default for PerfUiEntryRunningTime {
+impl<T: PerfUiTimeContext> Default for PerfUiEntryRunningTimeGeneric<T> {
     fn default() -> Self {
-        PerfUiEntryRunningTime {
+        PerfUiEntryRunningTimeGeneric {
             label: String::new(),
+            time_source: default(),
             start: None,
             format_hms: false,
+            auto_units: false,
+            unit_scale: default(),
             display_units: true,
             digits: 5,
             precision: 3,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            _time: PhantomData,
+        }
+    }
+}
+
+/// Event to signal that something of interest has happened.
+///
+/// Send this event whenever the thing you care about occurs
+/// (a level load, a GC pass, user input, ...). Any
+/// [`PerfUiEntryTimeSince`] configured with a matching `mark`
+/// will reset and count the time since the most recent such event.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PerfUiMark {
+    /// Which mark this event refers to.
+    pub label: String,
+}
+
+impl PerfUiMark {
+    /// Create a new event for the mark with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        PerfUiMark {
+            label: label.into(),
+        }
+    }
+}
+
+/// Resource to keep track of when each [`PerfUiMark`] was last received.
+///
+/// Times are stored as a duration since startup, as per Bevy's `Time<Real>::elapsed()`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiMarks {
+    last: HashMap<String, Duration>,
+}
+
+impl PerfUiMarks {
+    /// Get the time (duration since startup) when the given mark was last received.
+    ///
+    /// Returns `None` if no such mark has been received yet.
+    pub fn get(&self, label: &str) -> Option<Duration> {
+        self.last.get(label).copied()
+    }
+}
+
+/// Perf UI Entry to display the time since a [`PerfUiMark`] event was last received.
+///
+/// Until the first matching event arrives, there is nothing
+/// to measure from, so the value is unavailable.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryTimeSince {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// The label of the [`PerfUiMark`] events to track.
+    ///
+    /// Default: empty
+    pub mark: String,
+    /// If true, format time as HH:MM:SS (with optional fractional part as per `precision`).
+    /// If false, format time as seconds.
+    ///
+    /// Default: `false`
+    pub format_hms: bool,
+    /// If true, pick the most readable unit for the current value:
+    /// milliseconds below 1 second, seconds below 1 minute, and
+    /// MM:SS or HH:MM:SS (without fractional part) above that.
+    ///
+    /// `precision` applies to the seconds. Takes priority over `format_hms`.
+    ///
+    /// Default: `false`
+    pub auto_units: bool,
+    /// Which unit to display the time in.
+    ///
+    /// `digits` and `precision` apply to the value in this unit.
+    ///
+    /// Only used if `format_hms = false`.
+    ///
+    /// Default: [`TimeUnit::Seconds`]
+    pub unit_scale: TimeUnit,
+    /// Display the unit ("s", "ms", "µs") alongside the number.
+    ///
+    /// Only used if `format_hms = false`.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Only used if `format_hms = false`.
+    ///
+    /// Default: `5`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `3`
+    pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryTimeSince {
+    fn default() -> Self {
+        PerfUiEntryTimeSince {
+            label: String::new(),
+            mark: String::new(),
+            format_hms: false,
+            auto_units: false,
+            unit_scale: default(),
+            display_units: true,
+            digits: 5,
+            precision: 3,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryTimeSince {
+    /// Create a new entry tracking the mark with the given label.
+    pub fn new(mark: impl Into<String>) -> Self {
+        PerfUiEntryTimeSince {
+            mark: mark.into(),
+            ..default()
+        }
+    }
+}
+
+/// Event to signal the end of a lap, for [`PerfUiEntryLapTimer`].
+///
+/// Send this event every time the operation you are benchmarking
+/// completes. The time between two consecutive events with the same
+/// `label` is one lap. The very first event only starts the first lap.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PerfUiLap {
+    /// Which lap timer this event refers to.
+    pub label: String,
+}
+
+impl PerfUiLap {
+    /// Create a new event for the lap timer with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        PerfUiLap {
+            label: label.into(),
+        }
+    }
+}
+
+/// The lap times of one lap timer, see [`PerfUiLaps`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PerfUiLapTimes {
+    /// When the current lap started (duration since startup, as per `Time<Real>::elapsed()`).
+    pub anchor: Option<Duration>,
+    /// The duration of the most recent complete lap.
+    pub last: Option<Duration>,
+    /// The duration of the fastest lap so far.
+    pub best: Option<Duration>,
+}
+
+/// Resource to keep track of the lap times of each [`PerfUiLap`] label.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiLaps {
+    timers: HashMap<String, PerfUiLapTimes>,
+}
+
+impl PerfUiLaps {
+    /// Get the lap times of the lap timer with the given label.
+    ///
+    /// Returns `None` if no such lap has been received yet.
+    pub fn get(&self, label: &str) -> Option<&PerfUiLapTimes> {
+        self.timers.get(label)
+    }
+
+    /// Forget the best lap of the lap timer with the given label.
+    ///
+    /// The best lap will be tracked again starting with the next lap.
+    pub fn reset_best(&mut self, label: &str) {
+        if let Some(times) = self.timers.get_mut(label) {
+            times.best = None;
+        }
+    }
+
+    /// Forget everything about the lap timer with the given label.
+    ///
+    /// The next [`PerfUiLap`] event will start a new first lap.
+    pub fn reset(&mut self, label: &str) {
+        self.timers.remove(label);
+    }
+}
+
+/// Perf UI Entry to display lap times, as signaled by [`PerfUiLap`] events.
+///
+/// Displays the most recent lap, and optionally the best (fastest) lap,
+/// like "last: 1.23s best: 1.10s". Until the first lap is complete, the
+/// value is unavailable.
+///
+/// To clear the best lap, use [`PerfUiLaps::reset_best`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryLapTimer {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// The label of the [`PerfUiLap`] events to track.
+    ///
+    /// Default: empty
+    pub lap: String,
+    /// Also display the best (fastest) lap.
+    ///
+    /// Default: `true`
+    pub display_best: bool,
+    /// Number of digits to display for the integer (whole number) part of the seconds.
+    ///
+    /// Default: `3`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `2`
+    pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryLapTimer {
+    fn default() -> Self {
+        PerfUiEntryLapTimer {
+            label: String::new(),
+            lap: String::new(),
+            display_best: true,
+            digits: 3,
+            precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryLapTimer {
+    /// Create a new entry tracking the lap timer with the given label.
+    pub fn new(lap: impl Into<String>) -> Self {
+        PerfUiEntryLapTimer {
+            lap: lap.into(),
+            ..default()
+        }
+    }
+}
+
+/// Event to start an interval, for [`PerfUiEntryInterval`].
+///
+/// If the interval with this `label` is already running, it starts over.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PerfUiStart {
+    /// Which interval this event refers to.
+    pub label: String,
+}
+
+impl PerfUiStart {
+    /// Create a new event to start the interval with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        PerfUiStart {
+            label: label.into(),
+        }
+    }
+}
+
+/// Event to stop an interval, for [`PerfUiEntryInterval`].
+///
+/// Has no effect if the interval with this `label` is not running.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PerfUiStop {
+    /// Which interval this event refers to.
+    pub label: String,
+}
+
+impl PerfUiStop {
+    /// Create a new event to stop the interval with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        PerfUiStop {
+            label: label.into(),
+        }
+    }
+}
+
+/// The state of one interval, see [`PerfUiIntervals`].
+///
+/// Times are durations since startup, as per `Time<Real>::elapsed()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerfUiIntervalState {
+    /// Started, and not stopped yet.
+    Running {
+        /// When it was started.
+        start: Duration,
+    },
+    /// Stopped, after having run for this long.
+    Stopped {
+        /// The duration between the start and the stop.
+        duration: Duration,
+    },
+}
+
+impl PerfUiIntervalState {
+    /// How long the interval has been running for (if running), or
+    /// how long it ran for (if stopped).
+    ///
+    /// `now` is the current time (as per `Time<Real>::elapsed()`).
+    pub fn elapsed(&self, now: Duration) -> Duration {
+        match *self {
+            PerfUiIntervalState::Running { start } => now.saturating_sub(start),
+            PerfUiIntervalState::Stopped { duration } => duration,
+        }
+    }
+
+    /// Is the interval running?
+    pub fn is_running(&self) -> bool {
+        matches!(self, PerfUiIntervalState::Running { .. })
+    }
+}
+
+/// Resource to keep track of the intervals started and stopped
+/// by [`PerfUiStart`] and [`PerfUiStop`] events.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiIntervals {
+    intervals: HashMap<String, PerfUiIntervalState>,
+}
+
+impl PerfUiIntervals {
+    /// Get the state of the interval with the given label.
+    ///
+    /// Returns `None` if it has never been started.
+    pub fn get(&self, label: &str) -> Option<PerfUiIntervalState> {
+        self.intervals.get(label).copied()
+    }
+
+    /// Start the interval with the given label, at time `now`.
+    ///
+    /// The same as receiving a [`PerfUiStart`] event.
+    pub fn start(&mut self, label: &str, now: Duration) {
+        self.intervals.insert(label.to_owned(), PerfUiIntervalState::Running { start: now });
+    }
+
+    /// Stop the interval with the given label, at time `now`.
+    ///
+    /// The same as receiving a [`PerfUiStop`] event.
+    pub fn stop(&mut self, label: &str, now: Duration) {
+        if let Some(state) = self.intervals.get_mut(label) {
+            if let PerfUiIntervalState::Running { start } = *state {
+                *state = PerfUiIntervalState::Stopped {
+                    duration: now.saturating_sub(start),
+                };
+            }
+        }
+    }
+
+    /// Forget the interval with the given label.
+    pub fn reset(&mut self, label: &str) {
+        self.intervals.remove(label);
+    }
+}
+
+/// Perf UI Entry to display the time between a [`PerfUiStart`] and
+/// a [`PerfUiStop`] event.
+///
+/// While the interval is running, the time keeps increasing, live.
+/// Once it is stopped, the final duration stays displayed, until it
+/// is started again. Useful to time sequences that span many frames,
+/// like loading a level. Until the first start, the value is unavailable.
+///
+/// If both events are sent on the same frame, the start is handled
+/// first, so the interval is stopped (at zero).
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryInterval {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// The label of the [`PerfUiStart`]/[`PerfUiStop`] events to track.
+    ///
+    /// Default: empty
+    pub interval: String,
+    /// Display the value in this color while the interval is running.
+    ///
+    /// Makes it easy to tell a running interval from a stopped one.
+    ///
+    /// Default: `None`
+    pub color_running: Option<Color>,
+    /// Number of digits to display for the integer (whole number) part of the seconds.
+    ///
+    /// Default: `3`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Default: `2`
+    pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryInterval {
+    fn default() -> Self {
+        PerfUiEntryInterval {
+            label: String::new(),
+            interval: String::new(),
+            color_running: None,
+            digits: 3,
+            precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+impl PerfUiEntryInterval {
+    /// Create a new entry tracking the interval with the given label.
+    pub fn new(interval: impl Into<String>) -> Self {
+        PerfUiEntryInterval {
+            interval: interval.into(),
+            ..default()
         }
     }
 }
@@ -65,6 +818,8 @@ impl Default for PerfUiEntryRunningTime {
 /// This time is in UTC, unless you enable the optional `chrono` dependency on
 /// this crate. If `chrono` is enabled, it will be in local time.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryClock {
     /// Custom label. If empty (default), the default label will be used.
@@ -80,6 +835,44 @@ pub struct PerfUiEntryClock {
     ///
     /// Default: `0`
     pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -90,6 +883,12 @@ impl Default for PerfUiEntryClock {
             label: String::new(),
             prefer_utc: false,
             precision: 0,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
@@ -97,6 +896,8 @@ impl Default for PerfUiEntryClock {
 
 /// Perf UI Entry to display Bevy's Fixed Time Step duration.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryFixedTimeStep {
     /// Custom label. If empty (default), the default label will be used.
@@ -117,6 +918,44 @@ pub struct PerfUiEntryFixedTimeStep {
     ///
     /// Default: `2`
     pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -129,13 +968,26 @@ impl Default for PerfUiEntryFixedTimeStep {
             as_hz: true,
             digits: 3,
             precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
 }
 
 /// Perf UI Entry to display Bevy's Fixed Time overstep.
+///
+/// The overstep is how far the current frame is into the next fixed timestep.
+/// Useful for debugging jitter in games that interpolate rendering between
+/// fixed updates. It can be displayed as a duration, a percentage, or as
+/// the raw fraction (`0.0..1.0`) that you would use for interpolation.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryFixedOverstep {
     /// Custom label. If empty (default), the default label will be used.
@@ -148,14 +1000,58 @@ pub struct PerfUiEntryFixedOverstep {
     ///
     /// Default: `true`
     pub as_percent: bool,
+    /// Display it as a fraction of the timestep (`0.0..1.0`), with no units.
+    ///
+    /// If `true`, this takes priority over `as_percent`.
+    ///
+    /// Default: `false`
+    pub as_fraction: bool,
     /// Number of digits to display for the integer (whole number) part.
     ///
-    /// Default: `2`
+    /// Default: `3`
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `3`
+    /// Default: `2`
     pub precision: u8,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -166,61 +1062,468 @@ impl Default for PerfUiEntryFixedOverstep {
             label: String::new(),
             display_units: true,
             as_percent: true,
+            as_fraction: false,
             digits: 3,
             precision: 2,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
 }
 
-impl PerfUiEntry for PerfUiEntryRunningTime {
+/// Resource to count how many times `FixedUpdate` ran, for [`PerfUiEntryFixedSteps`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiFixedSteps {
+    counting: u32,
+    last_frame: u32,
+}
+
+impl PerfUiFixedSteps {
+    /// How many fixed timesteps ran during the current frame.
+    pub fn last_frame(&self) -> u32 {
+        self.last_frame
+    }
+}
+
+/// Perf UI Entry to display how many fixed timesteps ran during the frame.
+///
+/// Normally, this is 0 or 1 (depending on whether your framerate is higher
+/// or lower than the fixed timestep rate). Higher values mean that the app
+/// is "catching up": several fixed updates had to be run in one frame.
+/// If it stays above 1, your simulation cannot keep up.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryFixedSteps {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Enable color based on value.
+    ///
+    /// To disable (always use default color), set to empty `ColorGradient::default()`.
+    ///
+    /// Default: Green-Yellow-Red gradient between 1-2-4.
+    pub color_gradient: ColorGradient,
+    /// Custom color for good values (the good end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_good` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_good: Option<Color>,
+    /// Custom color for bad values (the bad end of `color_gradient`).
+    ///
+    /// If `None`, the `PerfUiRoot`'s `color_bad` is used, if set.
+    /// Otherwise, the gradient's own color is used.
+    ///
+    /// Default: `None`
+    pub color_bad: Option<Color>,
+    /// Highlight the value if above this threshold.
+    ///
+    /// Default: `1`
+    pub threshold_highlight: Option<u32>,
+    /// Is a higher or a lower value better?
+    ///
+    /// Controls which side of `threshold_highlight` is highlighted.
+    ///
+    /// Default: `MetricGoal::LowerIsBetter`
+    pub goal: MetricGoal,
+    /// Number of digits to display.
+    ///
+    /// Default: `2`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of steps.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of steps, before the change
+    /// (like "2 (+1)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryFixedSteps {
+    fn default() -> Self {
+        PerfUiEntryFixedSteps {
+            label: String::new(),
+            color_gradient: ColorGradient::new_preset_gyr(1.0, 2.0, 4.0).unwrap(),
+            color_good: None,
+            color_bad: None,
+            threshold_highlight: Some(1),
+            goal: MetricGoal::LowerIsBetter,
+            digits: 2,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
+        }
+    }
+}
+
+impl<T: PerfUiTimeContext> PerfUiEntry for PerfUiEntryRunningTimeGeneric<T> {
+    type Value = Duration;
+    type SystemParam = T::SystemParam;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Running Time"
+        } else {
+            &self.label
+        }
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(value.as_secs_f64())
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        if self.auto_units {
+            return width_hint_pretty_duration_adaptive(self.precision);
+        }
+        match (self.format_hms, self.display_units) {
+            (true, _) => width_hint_pretty_time(self.precision),
+            (false, true) => width_hint_pretty_float(self.digits, self.precision) + 1 + self.unit_scale.suffix().chars().count(),
+            (false, false) => width_hint_pretty_float(self.digits, self.precision),
+        }
+    }
+    fn update_value(
+        &self,
+        param: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let elapsed = T::elapsed(self.time_source, param);
+        if let Some(start) = self.start {
+            Some(elapsed.saturating_sub(start))
+        } else {
+            Some(elapsed)
+        }
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        if self.auto_units {
+            format_pretty_duration_adaptive(self.precision, *value)
+        } else if self.format_hms {
+            format_pretty_time(self.precision, *value)
+        } else {
+            let mut s = format_pretty_float(self.digits, self.precision, self.unit_scale.scale(*value));
+            if self.display_units {
+                s.push(' ');
+                s.push_str(self.unit_scale.suffix());
+            }
+            s
+        }
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryTimeSince {
     type Value = Duration;
-    type SystemParam = SRes<Time<Real>>;
+    type SystemParam = (SRes<Time<Real>>, SRes<PerfUiMarks>);
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            "Running Time"
+            "Time Since Mark"
         } else {
             &self.label
         }
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(value.as_secs_f64())
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
     fn width_hint(&self) -> usize {
+        if self.auto_units {
+            return width_hint_pretty_duration_adaptive(self.precision);
+        }
         match (self.format_hms, self.display_units) {
             (true, _) => width_hint_pretty_time(self.precision),
-            (false, true) => width_hint_pretty_float(self.digits, self.precision) + 2,
+            (false, true) => width_hint_pretty_float(self.digits, self.precision) + 1 + self.unit_scale.suffix().chars().count(),
             (false, false) => width_hint_pretty_float(self.digits, self.precision),
         }
     }
     fn update_value(
         &self,
-        time: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+        (time, marks): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        let elapsed = time.elapsed();
-        if let Some(start) = self.start {
-            Some(elapsed - start)
-        } else {
-            Some(elapsed)
-        }
+        let start = marks.get(&self.mark)?;
+        Some(time.elapsed().saturating_sub(start))
     }
     fn format_value(
         &self,
         value: &Self::Value,
     ) -> String {
-        if self.format_hms {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        if self.auto_units {
+            format_pretty_duration_adaptive(self.precision, *value)
+        } else if self.format_hms {
             format_pretty_time(self.precision, *value)
         } else {
-            let mut s = format_pretty_float(self.digits, self.precision, value.as_secs_f64());
+            let mut s = format_pretty_float(self.digits, self.precision, self.unit_scale.scale(*value));
             if self.display_units {
-                s.push_str(" s");
+                s.push(' ');
+                s.push_str(self.unit_scale.suffix());
             }
             s
         }
     }
 }
 
+impl PerfUiEntry for PerfUiEntryLapTimer {
+    /// (last lap, best lap)
+    type Value = (Duration, Option<Duration>);
+    type SystemParam = SRes<PerfUiLaps>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Lap Time"
+        } else {
+            &self.label
+        }
+    }
+    fn value_as_f64(
+        &self,
+        &(last, _): &Self::Value,
+    ) -> Option<f64> {
+        Some(last.as_secs_f64())
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        // "last: " + value + "s"
+        let w = 6 + width_hint_pretty_float(self.digits, self.precision) + 1;
+        if self.display_best {
+            // " best: " + value + "s"
+            w + 7 + width_hint_pretty_float(self.digits, self.precision) + 1
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        laps: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let times = laps.get(&self.lap)?;
+        Some((times.last?, times.best))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let &(last, best) = value;
+        let mut s = format!("last: {}s", format_pretty_float(self.digits, self.precision, last.as_secs_f64()));
+        if self.display_best {
+            if let Some(best) = best {
+                s.push_str(&format!(" best: {}s", format_pretty_float(self.digits, self.precision, best.as_secs_f64())));
+            }
+        }
+        s
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryInterval {
+    /// (elapsed time, is it running?)
+    type Value = (Duration, bool);
+    type SystemParam = (
+        SRes<PerfUiIntervals>,
+        SRes<Time<Real>>,
+    );
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Interval"
+        } else {
+            &self.label
+        }
+    }
+    fn value_color(
+        &self,
+        &(_, running): &Self::Value,
+    ) -> Option<Color> {
+        if running {
+            self.color_running
+        } else {
+            None
+        }
+    }
+    fn value_as_f64(
+        &self,
+        &(elapsed, _): &Self::Value,
+    ) -> Option<f64> {
+        Some(elapsed.as_secs_f64())
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        // value + "s"
+        width_hint_pretty_float(self.digits, self.precision) + 1
+    }
+    fn update_value(
+        &self,
+        (intervals, time): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let state = intervals.get(&self.interval)?;
+        Some((state.elapsed(time.elapsed()), state.is_running()))
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let &(elapsed, _) = value;
+        format!("{}s", format_pretty_float(self.digits, self.precision, elapsed.as_secs_f64()))
+    }
+}
+
+impl PerfUiEntryClock {
+    /// Is the time displayed in the local timezone (rather than UTC)?
+    ///
+    /// Both the default label and the displayed value are based on this,
+    /// so that the label always matches the timezone of the value.
+    fn uses_local_time(&self) -> bool {
+        cfg!(feature = "chrono") && !self.prefer_utc
+    }
+}
+
 impl PerfUiEntry for PerfUiEntryClock {
     // (h, m, s, nanos)
     type Value = (u32, u32, u32, u32);
@@ -228,7 +1531,7 @@ impl PerfUiEntry for PerfUiEntryClock {
 
     fn label(&self) -> &str {
         if self.label.is_empty() {
-            if cfg!(feature = "chrono") && !self.prefer_utc {
+            if self.uses_local_time() {
                 "Clock"
             } else {
                 "Clock (UTC)"
@@ -237,6 +1540,21 @@ impl PerfUiEntry for PerfUiEntryClock {
             &self.label
         }
     }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -248,7 +1566,7 @@ impl PerfUiEntry for PerfUiEntryClock {
         _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
         #[cfg(feature = "chrono")]
-        if !self.prefer_utc {
+        if self.uses_local_time() {
             return get_system_clock_local();
         }
 
@@ -256,10 +1574,21 @@ impl PerfUiEntry for PerfUiEntryClock {
     }
     fn format_value(
         &self,
-        &(h, m, s, nanos): &Self::Value,
+        value: &Self::Value,
     ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let &(h, m, s, nanos) = value;
         format_pretty_time_hms(self.precision, h, m, s, nanos)
     }
+    fn value_as_f64(
+        &self,
+        &(h, m, s, nanos): &Self::Value,
+    ) -> Option<f64> {
+        // seconds since midnight
+        Some(h as f64 * 3600.0 + m as f64 * 60.0 + s as f64 + nanos as f64 / 1e9)
+    }
 }
 
 impl PerfUiEntry for PerfUiEntryFixedTimeStep {
@@ -273,6 +1602,31 @@ impl PerfUiEntry for PerfUiEntryFixedTimeStep {
             &self.label
         }
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(if self.as_hz {
+            1_000_000_000f64 / value.as_nanos() as f64
+        } else {
+            value.as_nanos() as f64 / 1_000_000f64
+        })
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -294,6 +1648,9 @@ impl PerfUiEntry for PerfUiEntryFixedTimeStep {
         &self,
         value: &Self::Value,
     ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
         let (unit, value) = if self.as_hz {
             (" Hz", 1_000_000_000f64 / value.as_nanos() as f64)
         } else {
@@ -318,22 +1675,47 @@ impl PerfUiEntry for PerfUiEntryFixedOverstep {
             &self.label
         }
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
     fn width_hint(&self) -> usize {
         let w = width_hint_pretty_float(self.digits, self.precision);
-        match (self.as_percent, self.display_units) {
-            (true, _) => w + 1,
-            (false, true) => w + 3,
-            (false, false) => w,
+        if self.as_fraction || !self.display_units {
+            w
+        } else if self.as_percent {
+            w + 1
+        } else {
+            w + 3
         }
     }
     fn update_value(
         &self,
         time: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
     ) -> Option<Self::Value> {
-        Some(if self.as_percent {
+        Some(if self.as_fraction {
+            time.overstep_fraction_f64()
+        } else if self.as_percent {
             time.overstep_fraction_f64() * 100.0
         } else {
             time.overstep().as_secs_f64() * 1000.0
@@ -343,10 +1725,15 @@ impl PerfUiEntry for PerfUiEntryFixedOverstep {
         &self,
         value: &Self::Value,
     ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
         let mut s = format_pretty_float(self.digits, self.precision, *value);
-        if self.as_percent {
+        if self.as_fraction || !self.display_units {
+            // no units
+        } else if self.as_percent {
             s.push('%');
-        } else if self.display_units {
+        } else {
             s.push_str(" ms");
         }
         s
@@ -355,13 +1742,162 @@ impl PerfUiEntry for PerfUiEntryFixedOverstep {
 
 impl PerfUiEntryDisplayRange for PerfUiEntryFixedOverstep {
     fn max_value_hint(&self) -> Option<Self::Value> {
-        Some(100.0)
+        if self.as_fraction {
+            Some(1.0)
+        } else {
+            Some(100.0)
+        }
     }
     fn min_value_hint(&self) -> Option<Self::Value> {
         Some(0.0)
     }
 }
 
+impl PerfUiEntry for PerfUiEntryFixedSteps {
+    type Value = u32;
+    type SystemParam = SRes<PerfUiFixedSteps>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Fixed Steps"
+        } else {
+            &self.label
+        }
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
+    }
+    fn update_value(
+        &self,
+        steps: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let steps = steps.last_frame;
+        if self.show_delta {
+            self.delta.push(steps as i64);
+        }
+        Some(steps)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+    fn value_color(
+        &self,
+        value: &Self::Value,
+    ) -> Option<Color> {
+        self.value_color_with_palette(value, None, None)
+    }
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        self.color_gradient.get_color_for_value_with_ends(*value as f32, self.goal, self.color_good.or(good), self.color_bad.or(bad))
+    }
+    fn value_highlight(
+        &self,
+        value: &Self::Value,
+    ) -> bool {
+        self.threshold_highlight
+            .map(|t| self.goal.is_worse(*value, t))
+            .unwrap_or(false)
+    }
+}
+
+impl PerfUiEntryDisplayRange for PerfUiEntryFixedSteps {
+    fn max_value_hint(&self) -> Option<Self::Value> {
+        self.color_gradient.max_stop()
+            .map(|(v, _)| *v as u32)
+    }
+    fn min_value_hint(&self) -> Option<Self::Value> {
+        Some(0)
+    }
+}
+
+pub(crate) fn count_perf_ui_fixed_steps(
+    mut steps: ResMut<PerfUiFixedSteps>,
+) {
+    steps.counting += 1;
+}
+
+pub(crate) fn record_perf_ui_fixed_steps(
+    mut steps: ResMut<PerfUiFixedSteps>,
+) {
+    steps.last_frame = std::mem::take(&mut steps.counting);
+}
+
+pub(crate) fn record_perf_ui_marks(
+    time: Res<Time<Real>>,
+    mut marks: ResMut<PerfUiMarks>,
+    mut evr_mark: EventReader<PerfUiMark>,
+) {
+    for ev in evr_mark.read() {
+        marks.last.insert(ev.label.clone(), time.elapsed());
+    }
+}
+
+pub(crate) fn record_perf_ui_laps(
+    time: Res<Time<Real>>,
+    mut laps: ResMut<PerfUiLaps>,
+    mut evr_lap: EventReader<PerfUiLap>,
+) {
+    for ev in evr_lap.read() {
+        let now = time.elapsed();
+        let times = laps.timers.entry(ev.label.clone()).or_default();
+        if let Some(anchor) = times.anchor {
+            let lap = now.saturating_sub(anchor);
+            times.last = Some(lap);
+            times.best = Some(times.best.map_or(lap, |best| best.min(lap)));
+        }
+        times.anchor = Some(now);
+    }
+}
+
+pub(crate) fn record_perf_ui_intervals(
+    time: Res<Time<Real>>,
+    mut intervals: ResMut<PerfUiIntervals>,
+    mut evr_start: EventReader<PerfUiStart>,
+    mut evr_stop: EventReader<PerfUiStop>,
+) {
+    let now = time.elapsed();
+    for ev in evr_start.read() {
+        intervals.start(&ev.label, now);
+    }
+    for ev in evr_stop.read() {
+        intervals.stop(&ev.label, now);
+    }
+}
+
 #[cfg(feature = "chrono")]
 fn get_system_clock_local() -> Option<(u32, u32, u32, u32)> {
     use chrono::Timelike;
@@ -374,11 +1910,235 @@ fn get_system_clock_local() -> Option<(u32, u32, u32, u32)> {
 }
 
 fn get_system_clock_utc() -> Option<(u32, u32, u32, u32)> {
-    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(system_time_to_utc_hms(std::time::SystemTime::now()))
+}
+
+/// Convert a `SystemTime` to a UTC time of day (h, m, s, nanos).
+///
+/// If the time is before the UNIX epoch (which can happen if the
+/// system clock is skewed), a warning is logged and the time
+/// is clamped to the epoch (midnight).
+fn system_time_to_utc_hms(time: std::time::SystemTime) -> (u32, u32, u32, u32) {
+    let now = match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(d) => d,
+        Err(e) => {
+            warn_once!(
+                "System clock is {:?} before the UNIX epoch! Perf UI Clock will display midnight.",
+                e.duration(),
+            );
+            Duration::ZERO
+        }
+    };
     let secs = now.as_secs();
     let h = (secs / 3600) % 24;
     let m = (secs / 60) % 60;
     let s = secs % 60;
     let nanos = now.subsec_nanos();
-    Some((h as u32, m as u32, s as u32, nanos))
+    (h as u32, m as u32, s as u32, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use bevy::ecs::system::{RunSystemOnce, SystemState};
+
+    use super::*;
+
+    #[test]
+    fn clock_before_epoch_is_clamped() {
+        // a skewed clock, an hour before the epoch
+        assert_eq!(system_time_to_utc_hms(UNIX_EPOCH - Duration::from_secs(3600)), (0, 0, 0, 0));
+        assert_eq!(system_time_to_utc_hms(UNIX_EPOCH), (0, 0, 0, 0));
+        // the next day, at 01:01:02
+        let time = UNIX_EPOCH + Duration::new(25 * 3600 + 62, 5);
+        assert_eq!(system_time_to_utc_hms(time), (1, 1, 2, 5));
+    }
+
+    /// Assert that the clock displays the same time of day as `expected`
+    /// (give or take a second, as the clock moves on between the two).
+    fn assert_displays(entry: &PerfUiEntryClock, expected: (u32, u32, u32, u32)) {
+        let displayed = entry.value_as_f64(&entry.update_value(&mut ()).unwrap()).unwrap();
+        let expected = entry.value_as_f64(&expected).unwrap();
+        let diff = (displayed - expected).rem_euclid(86400.0);
+        assert!(diff.min(86400.0 - diff) < 2.0, "displayed {displayed}, expected {expected}");
+    }
+
+    fn clock(prefer_utc: bool) -> PerfUiEntryClock {
+        PerfUiEntryClock {
+            prefer_utc,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn clock_prefer_utc() {
+        // UTC, with or without chrono
+        let entry = clock(true);
+        assert_eq!(entry.label(), "Clock (UTC)");
+        assert_displays(&entry, get_system_clock_utc().unwrap());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn clock_local_with_chrono() {
+        let entry = clock(false);
+        assert_eq!(entry.label(), "Clock");
+        assert_displays(&entry, get_system_clock_local().unwrap());
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn clock_local_without_chrono() {
+        // the local timezone is unknown, so this falls back to UTC
+        let entry = clock(false);
+        assert_eq!(entry.label(), "Clock (UTC)");
+        assert_displays(&entry, get_system_clock_utc().unwrap());
+    }
+
+    #[test]
+    fn clock_custom_label() {
+        for prefer_utc in [false, true] {
+            let entry = PerfUiEntryClock {
+                label: "Time".into(),
+                ..clock(prefer_utc)
+            };
+            assert_eq!(entry.label(), "Time");
+        }
+    }
+
+    /// Format the value of a running time entry, after `elapsed` has passed.
+    fn running_time<T: PerfUiTimeContext>(entry: PerfUiEntryRunningTimeGeneric<T>, elapsed: Duration) -> String {
+        let mut world = World::new();
+        advance_time(&mut world, elapsed);
+        let mut state = SystemState::<T::SystemParam>::new(&mut world);
+        let value = entry.update_value(&mut state.get_mut(&mut world)).unwrap();
+        entry.format_value(&value).trim().to_owned()
+    }
+
+    #[test]
+    fn running_time_formatting() {
+        let secs = Duration::from_secs;
+        let millis = Duration::from_millis;
+        assert_eq!(running_time(PerfUiEntryRunningTime::default(), secs(83)), "83.000 s");
+        assert_eq!(running_time(PerfUiEntryRunningTime::default(), Duration::ZERO), "0.000 s");
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            format_hms: true,
+            precision: 0,
+            ..default()
+        }, secs(83)), "1:23");
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            format_hms: true,
+            precision: 2,
+            ..default()
+        }, millis(3_723_500)), "1:02:03.50");
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            unit_scale: TimeUnit::Millis,
+            precision: 1,
+            ..default()
+        }, millis(1500)), "1500.0 ms");
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            display_units: false,
+            digits: 2,
+            precision: 1,
+            ..default()
+        }, secs(100)), "99.9");
+        // relative to `start`
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            start: Some(secs(60)),
+            ..default()
+        }, secs(83)), "23.000 s");
+        for (elapsed, expected) in [(millis(950), "950 ms"), (millis(12_345), "12.3 s"), (secs(245), "4:05")] {
+            assert_eq!(running_time(PerfUiEntryRunningTime {
+                auto_units: true,
+                precision: 1,
+                ..default()
+            }, elapsed), expected);
+        }
+        // other clocks
+        assert_eq!(running_time(PerfUiEntryRunningTime {
+            time_source: TimeSource::Virtual,
+            ..default()
+        }, secs(5)), "5.000 s");
+        assert_eq!(running_time(PerfUiEntryRunningTimeVirtual::default(), secs(5)), "5.000 s");
+    }
+
+    #[test]
+    fn interval_start_stop_transitions() {
+        let secs = Duration::from_secs;
+        let mut intervals = PerfUiIntervals::default();
+        // stopping an interval that was never started does nothing
+        intervals.stop("Loading", secs(1));
+        assert_eq!(intervals.get("Loading"), None);
+
+        intervals.start("Loading", secs(2));
+        let state = intervals.get("Loading").unwrap();
+        assert_eq!(state, PerfUiIntervalState::Running { start: secs(2) });
+        assert!(state.is_running());
+        assert_eq!(state.elapsed(secs(5)), secs(3));
+
+        intervals.stop("Loading", secs(7));
+        let state = intervals.get("Loading").unwrap();
+        assert_eq!(state, PerfUiIntervalState::Stopped { duration: secs(5) });
+        assert!(!state.is_running());
+        assert_eq!(state.elapsed(secs(100)), secs(5));
+
+        // stopping again keeps the first duration
+        intervals.stop("Loading", secs(9));
+        assert_eq!(intervals.get("Loading"), Some(PerfUiIntervalState::Stopped { duration: secs(5) }));
+
+        // restarting runs from the new start
+        intervals.start("Loading", secs(10));
+        assert_eq!(intervals.get("Loading"), Some(PerfUiIntervalState::Running { start: secs(10) }));
+        assert_eq!(intervals.get("Other"), None);
+
+        intervals.reset("Loading");
+        assert_eq!(intervals.get("Loading"), None);
+    }
+
+    #[test]
+    fn interval_events_on_the_same_frame() {
+        let mut world = World::new();
+        world.init_resource::<PerfUiIntervals>();
+        world.init_resource::<Events<PerfUiStart>>();
+        world.init_resource::<Events<PerfUiStop>>();
+        advance_time(&mut world, Duration::from_secs(1));
+        world.send_event(PerfUiStop { label: "Loading".into() });
+        world.send_event(PerfUiStart { label: "Loading".into() });
+        world.run_system_once(record_perf_ui_intervals).unwrap();
+        // the start is handled first, so the interval is stopped at zero
+        assert_eq!(
+            world.resource::<PerfUiIntervals>().get("Loading"),
+            Some(PerfUiIntervalState::Stopped { duration: Duration::ZERO }),
+        );
+    }
+
+    #[test]
+    fn fixed_steps_show_delta() {
+        let mut world = World::new();
+        world.init_resource::<PerfUiFixedSteps>();
+        let mut state = SystemState::<<PerfUiEntryFixedSteps as PerfUiEntry>::SystemParam>::new(&mut world);
+        let entry = PerfUiEntryFixedSteps {
+            digits: 2,
+            show_delta: true,
+            ..default()
+        };
+        let mut update = |world: &mut World, steps: u32| {
+            world.resource_mut::<PerfUiFixedSteps>().last_frame = steps;
+            let value = entry.update_value(&mut state.get_mut(world)).unwrap();
+            entry.format_value(&value)
+        };
+        assert_eq!(update(&mut world, 2), "  2 (--)");
+        assert_eq!(update(&mut world, 3), "  3 (+1)");
+        assert_eq!(update(&mut world, 1), "  1 (-2)");
+    }
+
+    #[test]
+    fn time_since_formatter() {
+        let entry = PerfUiEntryTimeSince {
+            formatter: Some(PerfUiFormatter::new(|secs| format!("{secs:.1}s ago"))),
+            ..default()
+        };
+        assert_eq!(entry.format_value(&Duration::from_millis(2500)), "2.5s ago");
+    }
 }
@@ -3,9 +3,11 @@
 use bevy::prelude::*;
 use bevy::ecs::system::lifetimeless::SQuery;
 use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
 use bevy::window::PresentMode;
-use bevy::window::PrimaryWindow;
+use bevy::window::{Monitor, PrimaryMonitor, PrimaryWindow};
 use bevy::window::WindowMode;
+use bevy::window::WindowPosition;
 
 use crate::prelude::*;
 use crate::entry::*;
@@ -13,6 +15,8 @@ use crate::utils::*;
 
 /// Perf UI Entry to display the window mode (windowed, fullscreen, etc).
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryWindowMode {
     /// Custom label. If empty (default), the default label will be used.
@@ -21,6 +25,35 @@ pub struct PerfUiEntryWindowMode {
     ///
     /// If `None` (the default), the primary window is selected.
     pub window: Option<Entity>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -30,6 +63,11 @@ impl Default for PerfUiEntryWindowMode {
         PerfUiEntryWindowMode {
             label: String::new(),
             window: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
@@ -37,6 +75,8 @@ impl Default for PerfUiEntryWindowMode {
 
 /// Perf UI Entry to display the window present mode (vsync).
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryWindowPresentMode {
     /// Custom label. If empty (default), the default label will be used.
@@ -45,6 +85,35 @@ pub struct PerfUiEntryWindowPresentMode {
     ///
     /// If `None` (the default), the primary window is selected.
     pub window: Option<Entity>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -54,6 +123,11 @@ impl Default for PerfUiEntryWindowPresentMode {
         PerfUiEntryWindowPresentMode {
             label: String::new(),
             window: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
@@ -61,6 +135,8 @@ impl Default for PerfUiEntryWindowPresentMode {
 
 /// Perf UI Entry to display the window size / resolution.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryWindowScaleFactor {
     /// Custom label. If empty (default), the default label will be used.
@@ -75,8 +151,65 @@ pub struct PerfUiEntryWindowScaleFactor {
     pub digits: u8,
     /// Number of digits to display for the fractional (after the decimal point) part.
     ///
-    /// Default: `2`
-    pub precision: u8,
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(2)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -87,7 +220,132 @@ impl Default for PerfUiEntryWindowScaleFactor {
             label: String::new(),
             window: None,
             digits: 2,
-            precision: 2,
+            precision: Precision::Fixed(2),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+/// Perf UI Entry to display the refresh rate of the monitor the window is on.
+///
+/// Useful to compare with the FPS, to tell if you are limited by vsync.
+///
+/// The monitor is found from the position of the window. If the position
+/// is not known, the primary monitor is used. If the windowing backend
+/// does not report the refresh rate, the value is unavailable.
+///
+/// The monitors are checked every frame, so the value follows the window
+/// when it is moved to another monitor, or when the monitor is changed.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryRefreshRate {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Display the value for the specified window (in a multi-window application).
+    ///
+    /// If `None` (the default), the primary window is selected.
+    pub window: Option<Entity>,
+    /// Display the unit ("Hz") alongside the number.
+    ///
+    /// Default: `true`
+    pub display_units: bool,
+    /// Number of digits to display for the integer (whole number) part.
+    ///
+    /// Default: `3`
+    pub digits: u8,
+    /// Number of digits to display for the fractional (after the decimal point) part.
+    ///
+    /// Use [`Precision::Auto`] for values that vary widely in magnitude.
+    ///
+    /// Default: `Precision::Fixed(0)`
+    pub precision: Precision,
+    /// How to display values that are too large for `digits`.
+    ///
+    /// Default: `Notation::Fixed`
+    pub notation: Notation,
+    /// Remove trailing zeros after the decimal point (like `5` instead of `5.00`).
+    ///
+    /// The value stays padded to the same width, so it remains aligned.
+    ///
+    /// Default: `false`
+    pub trim_trailing_zeros: bool,
+    /// The character to pad the value with, to fill its width.
+    ///
+    /// Use `'0'` for zero-padded numbers (like `0042.00`). For negative
+    /// values, the `-` comes before the padding (like `-042.00`).
+    ///
+    /// Default: `' '` (space)
+    pub pad_char: char,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+}
+
+impl Default for PerfUiEntryRefreshRate {
+    fn default() -> Self {
+        PerfUiEntryRefreshRate {
+            label: String::new(),
+            window: None,
+            display_units: true,
+            digits: 3,
+            precision: Precision::Fixed(0),
+            notation: Notation::Fixed,
+            trim_trailing_zeros: false,
+            pad_char: ' ',
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
@@ -95,6 +353,8 @@ impl Default for PerfUiEntryWindowScaleFactor {
 
 /// Perf UI Entry to display the window size / resolution.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryWindowResolution {
     /// Custom label. If empty (default), the default label will be used.
@@ -102,6 +362,7 @@ pub struct PerfUiEntryWindowResolution {
     /// Separate the X and Y values by this string.
     ///
     /// Default: `"x"`.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub separator: &'static str,
     /// Display the unit ("px") alongside the numbers.
     ///
@@ -127,6 +388,35 @@ pub struct PerfUiEntryWindowResolution {
     ///
     /// Default: `8` (assuming common up to 4-digit resolutions, precision = 0)
     pub width: u8,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -142,6 +432,11 @@ impl Default for PerfUiEntryWindowResolution {
             window: None,
             precision: 0,
             width: 8,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
         }
     }
@@ -149,6 +444,8 @@ impl Default for PerfUiEntryWindowResolution {
 
 /// Perf UI Entry to display the current coordinates of the mouse cursor.
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[require(PerfUiRoot)]
 pub struct PerfUiEntryCursorPosition {
     /// Custom label. If empty (default), the default label will be used.
@@ -156,6 +453,7 @@ pub struct PerfUiEntryCursorPosition {
     /// Separate the X and Y values by this string.
     ///
     /// Default: `", "`.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub separator: &'static str,
     /// Display the unit ("px") alongside the numbers.
     ///
@@ -181,6 +479,35 @@ pub struct PerfUiEntryCursorPosition {
     ///
     /// Default: `8` (assuming common up to 4-digit resolutions, precision = 0)
     pub width: u8,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
     /// Sort Key (control where the entry will appear in the Perf UI).
     pub sort_key: i32,
 }
@@ -196,7 +523,104 @@ impl Default for PerfUiEntryCursorPosition {
             window: None,
             precision: 0,
             width: 8,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
+            sort_key: next_sort_key(),
+        }
+    }
+}
+
+/// Perf UI Entry to display the number of open windows.
+///
+/// Useful for multi-window applications, to confirm that windows
+/// are opened and closed as expected.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[require(PerfUiRoot)]
+pub struct PerfUiEntryWindowCount {
+    /// Custom label. If empty (default), the default label will be used.
+    pub label: String,
+    /// Number of digits to display.
+    ///
+    /// Default: `2`
+    pub digits: u8,
+    /// Display the change since the previous update (like "+12"),
+    /// instead of the number of windows.
+    ///
+    /// Until there is a previous value, "--" is displayed.
+    ///
+    /// Default: `false`
+    pub show_delta: bool,
+    /// With `show_delta`, also display the number of windows, before the change
+    /// (like "2 (+1)").
+    ///
+    /// Default: `true`
+    pub show_delta_with_value: bool,
+    /// Custom function to format the value, instead of the built-in formatting.
+    ///
+    /// Called with the value as a plain number (see `value_as_f64`), and
+    /// replaces the whole displayed text (including the units).
+    /// See [`PerfUiFormatter`] for a note about performance.
+    ///
+    /// Default: `None`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub formatter: Option<PerfUiFormatter>,
+    /// Custom background color for the entry's row.
+    ///
+    /// If `None`, the root's `inner_background_color` will be used.
+    ///
+    /// Default: `None`
+    pub row_background: Option<Color>,
+    /// Always display the value in this color.
+    ///
+    /// If set, the color based on the value (such as the color gradient) is ignored.
+    ///
+    /// Default: `None`
+    pub fixed_color: Option<Color>,
+    /// Update the value less often than every frame.
+    ///
+    /// Default: `None` (update every frame)
+    pub throttle: Option<PerfUiThrottle>,
+    /// Display the value as stale (dimmed), if it has not been updated for this long.
+    ///
+    /// Useful to tell apart a value that is not being updated anymore.
+    ///
+    /// Default: `None`
+    pub stale_after: Option<Duration>,
+    /// Display the entry.
+    ///
+    /// If `false`, the entry is hidden (as if it was removed), and
+    /// its value is not updated. Useful to toggle it at runtime.
+    ///
+    /// Default: `true`
+    pub enabled: bool,
+    /// Sort Key (control where the entry will appear in the Perf UI).
+    pub sort_key: i32,
+    /// Internal state for `show_delta`. Leave it as the default.
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub delta: DeltaTracker,
+}
+
+impl Default for PerfUiEntryWindowCount {
+    fn default() -> Self {
+        PerfUiEntryWindowCount {
+            label: String::new(),
+            digits: 2,
+            show_delta: false,
+            show_delta_with_value: true,
+            formatter: None,
+            row_background: None,
+            fixed_color: None,
+            throttle: None,
+            stale_after: None,
+            enabled: true,
             sort_key: next_sort_key(),
+            delta: DeltaTracker::default(),
         }
     }
 }
@@ -215,6 +639,21 @@ impl PerfUiEntry for PerfUiEntryWindowMode {
             &self.label
         }
     }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -244,6 +683,21 @@ impl PerfUiEntry for PerfUiEntryWindowPresentMode {
             &self.label
         }
     }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -273,11 +727,32 @@ impl PerfUiEntry for PerfUiEntryWindowScaleFactor {
             &self.label
         }
     }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
     fn width_hint(&self) -> usize {
-        width_hint_pretty_float(self.digits, self.precision)
+        width_hint_pretty_float_precision(self.digits, self.precision)
     }
     fn update_value(
         &self,
@@ -293,7 +768,97 @@ impl PerfUiEntry for PerfUiEntryWindowScaleFactor {
         &self,
         value: &Self::Value,
     ) -> String {
-        format_pretty_float(self.digits, self.precision, *value as f64)
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value as f64)
+    }
+}
+
+impl PerfUiEntry for PerfUiEntryRefreshRate {
+    /// Refresh rate in Hz
+    type Value = f64;
+    type SystemParam = (
+        SQuery<&'static Window, With<PrimaryWindow>>,
+        SQuery<&'static Window>,
+        SQuery<(&'static Monitor, Has<PrimaryMonitor>)>,
+    );
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Refresh Rate"
+        } else {
+            &self.label
+        }
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        let w = width_hint_pretty_float_precision(self.digits, self.precision);
+        if self.display_units {
+            w + 3
+        } else {
+            w
+        }
+    }
+    fn update_value(
+        &self,
+        (q_primary, q_any, q_monitor): &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let window = if let Some(e) = self.window {
+            q_any.get(e).ok()?
+        } else {
+            q_primary.get_single().ok()?
+        };
+        // the monitor that contains the center of the window
+        let on_monitor = if let WindowPosition::At(pos) = window.position {
+            let center = pos + (window.resolution.physical_size() / 2).as_ivec2();
+            q_monitor.iter().find(|(monitor, _)| {
+                let min = monitor.physical_position;
+                let max = min + monitor.physical_size().as_ivec2();
+                center.cmpge(min).all() && center.cmplt(max).all()
+            })
+        } else {
+            None
+        };
+        let (monitor, _) = on_monitor
+            .or_else(|| q_monitor.iter().find(|(_, primary)| *primary))?;
+        monitor.refresh_rate_millihertz.map(|mhz| mhz as f64 / 1000.0)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        let mut s = format_pretty_float_options(self.digits, self.precision, self.notation, self.trim_trailing_zeros, self.pad_char, *value);
+        if self.display_units {
+            s.push_str(" Hz");
+        }
+        s
     }
 }
 
@@ -311,6 +876,21 @@ impl PerfUiEntry for PerfUiEntryWindowResolution {
             &self.label
         }
     }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -391,6 +971,21 @@ impl PerfUiEntry for PerfUiEntryCursorPosition {
             &self.label
         }
     }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
     fn sort_key(&self) -> i32 {
         self.sort_key
     }
@@ -444,3 +1039,62 @@ impl PerfUiEntry for PerfUiEntryCursorPosition {
         }
     }
 }
+
+impl PerfUiEntry for PerfUiEntryWindowCount {
+    type Value = usize;
+    type SystemParam = SQuery<&'static Window>;
+
+    fn label(&self) -> &str {
+        if self.label.is_empty() {
+            "Windows"
+        } else {
+            &self.label
+        }
+    }
+    fn value_as_f64(
+        &self,
+        value: &Self::Value,
+    ) -> Option<f64> {
+        Some(*value as f64)
+    }
+    fn row_background(&self) -> Option<Color> {
+        self.row_background
+    }
+    fn fixed_color(&self) -> Option<Color> {
+        self.fixed_color
+    }
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.throttle
+    }
+    fn stale_after(&self) -> Option<Duration> {
+        self.stale_after
+    }
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+    fn sort_key(&self) -> i32 {
+        self.sort_key
+    }
+    fn width_hint(&self) -> usize {
+        DeltaTracker::width_hint(self.digits, self.show_delta, self.show_delta_with_value)
+    }
+    fn update_value(
+        &self,
+        q_window: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+    ) -> Option<Self::Value> {
+        let count = q_window.iter().count();
+        if self.show_delta {
+            self.delta.push(count as i64);
+        }
+        Some(count)
+    }
+    fn format_value(
+        &self,
+        value: &Self::Value,
+    ) -> String {
+        if let (Some(formatter), Some(value)) = (&self.formatter, self.value_as_f64(value)) {
+            return formatter.format(value);
+        }
+        self.delta.format_value(self.digits, *value as i64, self.show_delta, self.show_delta_with_value)
+    }
+}
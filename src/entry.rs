@@ -1,7 +1,10 @@
 //! Common framework for Perf UI Entry types (data providers)
 
+use std::any::TypeId;
+
 use bevy::prelude::*;
 use bevy::ecs::system::SystemParam;
+use bevy::utils::Duration;
 
 #[allow(unused_imports)]
 use crate::prelude::*;
@@ -56,6 +59,35 @@ pub trait PerfUiEntry: Component {
         format!("{:?}", value)
     }
 
+    /// Optional: display the value as multiple separate parts.
+    ///
+    /// Some entries naturally have several values (such as min/max). Rather
+    /// than formatting them into one string, they can be displayed as
+    /// `value_parts` separate text nodes in the same row, which stay
+    /// aligned with each other.
+    ///
+    /// This must not change while the entry is displayed, as the UI
+    /// nodes are only spawned once. Defaults to 1.
+    fn value_parts(&self) -> usize {
+        1
+    }
+
+    /// Optional: format the raw value into multiple strings for display.
+    ///
+    /// Should return `value_parts` strings, one for each part. If you
+    /// return fewer, the remaining parts will display nothing.
+    ///
+    /// `format_value` is still used in places where a single string
+    /// is needed, such as [`PerfUiDisplayCache`](crate::metrics::PerfUiDisplayCache).
+    ///
+    /// If unimplemented, the single string from `format_value` is used.
+    fn format_value_parts(
+        &self,
+        value: &Self::Value,
+    ) -> Vec<String> {
+        vec![self.format_value(value)]
+    }
+
     /// Optional: set a custom color for the value to display.
     ///
     /// `None` means the value should be displayed using the default color.
@@ -69,6 +101,23 @@ pub trait PerfUiEntry: Component {
         None
     }
 
+    /// Optional: like `value_color`, but with colors for the good and bad
+    /// ends of the entry's color range.
+    ///
+    /// This is what the Perf UI actually calls, passing the `PerfUiRoot`'s
+    /// `color_good`/`color_bad`. Entries that have their own `color_good`/`color_bad`
+    /// should prefer them over the ones passed in.
+    ///
+    /// The default implementation ignores the colors and calls `value_color`.
+    fn value_color_with_palette(
+        &self,
+        value: &Self::Value,
+        _good: Option<Color>,
+        _bad: Option<Color>,
+    ) -> Option<Color> {
+        self.value_color(value)
+    }
+
     /// Optional: set whether the value should be displayed highlighted.
     ///
     /// Called every frame after `update_value`, unless it returned `None`.
@@ -80,6 +129,64 @@ pub trait PerfUiEntry: Component {
         false
     }
 
+    /// Optional: update the value less often than every frame.
+    ///
+    /// If this returns `Some`, `update_value` will only be called when
+    /// the throttle is due, and the previous value will be kept in between.
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        None
+    }
+
+    /// Optional: display the value as stale, if it has not been updated for this long.
+    ///
+    /// Stale values are displayed dimmed. A value is fresh whenever
+    /// `update_value` returns it. If `update_value` returns `None`, the
+    /// last value keeps being displayed (instead of the error text),
+    /// until it is updated again, so that it can become stale.
+    fn stale_after(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Optional: is the entry enabled?
+    ///
+    /// Disabled entries are not displayed, and their values are not updated.
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    /// Optional: always display the value in a fixed color.
+    ///
+    /// If this returns `Some`, it takes priority over `value_color`.
+    fn fixed_color(&self) -> Option<Color> {
+        None
+    }
+
+    /// Optional: set a custom background color for the entry's row.
+    ///
+    /// `None` means the row should use the default background color
+    /// (`inner_background_color` of the [`PerfUiRoot`]).
+    ///
+    /// Highlighted entries always use `inner_background_color_highlight`.
+    fn row_background(&self) -> Option<Color> {
+        None
+    }
+
+    /// Optional: provide the value as a plain number.
+    ///
+    /// This is used by features that need to work with the values of
+    /// any entry type generically, such as alerts.
+    ///
+    /// `None` means the value has no meaningful numeric representation.
+    ///
+    /// Called after `update_value`, unless it returned `None`.
+    /// The `value` parameter is whatever that function returned.
+    fn value_as_f64(
+        &self,
+        _value: &Self::Value,
+    ) -> Option<f64> {
+        None
+    }
+
     /// Optional: provide a desired width for the value string.
     ///
     /// The formatted value will be padded with spaces. This allows
@@ -90,6 +197,63 @@ pub trait PerfUiEntry: Component {
     fn width_hint(&self) -> usize {
         0
     }
+
+    /// Optional: provide a desired width for each value part.
+    ///
+    /// See `value_parts`. If unimplemented, `width_hint` is used for every part.
+    fn width_hint_part(&self, _part: usize) -> usize {
+        self.width_hint()
+    }
+}
+
+/// Settings for updating an entry less often than every frame.
+///
+/// Useful for entries that are expensive to update, or whose
+/// value would change too quickly to be readable.
+///
+/// If you have many throttled entries with the same interval, they will
+/// all update on the same frame. Give them different `phase_offset`s to
+/// spread out the cost over multiple frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerfUiThrottle {
+    /// How often to update the value.
+    pub interval: Duration,
+    /// Shift the moment of the update within each interval.
+    ///
+    /// For example, with an `interval` of 1 second and a `phase_offset`
+    /// of 250 ms, the value is updated at 0.25 s, 1.25 s, 2.25 s, ...
+    pub phase_offset: Duration,
+}
+
+impl PerfUiThrottle {
+    /// Update the value once every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        PerfUiThrottle {
+            interval,
+            phase_offset: Duration::ZERO,
+        }
+    }
+
+    /// Set the phase offset.
+    pub fn with_phase_offset(mut self, phase_offset: Duration) -> Self {
+        self.phase_offset = phase_offset;
+        self
+    }
+
+    /// Is an update due on the current frame?
+    ///
+    /// `now` and `delta` should come from `Time<Real>`.
+    /// Always true on the first frame (when `delta` is zero).
+    pub fn is_due(&self, now: Duration, delta: Duration) -> bool {
+        let interval = self.interval.as_nanos();
+        if interval == 0 || delta.is_zero() {
+            return true;
+        }
+        let offset = self.phase_offset.as_nanos() % interval;
+        let period = |t: Duration| (t.as_nanos() + interval - offset) / interval;
+        period(now) != period(now.saturating_sub(delta))
+    }
 }
 
 /// Extension to [`PerfUiEntry`] to provide an expected range of values.
@@ -113,3 +277,83 @@ pub trait PerfUiEntryDisplayRange: PerfUiEntry {
     /// If the value is below this, it may be clipped in the UI.
     fn min_value_hint(&self) -> Option<Self::Value>;
 }
+
+/// Information about a Perf UI Entry type that has been registered with the app.
+///
+/// See [`PerfUiEntryRegistry`].
+#[derive(Debug, Clone)]
+pub struct PerfUiEntryInfo {
+    /// The `TypeId` of the entry component.
+    pub type_id: TypeId,
+    /// The Rust type name of the entry component (for debugging).
+    pub type_name: &'static str,
+    /// The label of the entry, when created with default settings.
+    ///
+    /// Only known for entry types registered with `add_perf_ui_entry_info`.
+    pub default_label: Option<String>,
+    constructor: Option<fn(&mut EntityCommands)>,
+}
+
+impl PerfUiEntryInfo {
+    /// Can this entry be created with default settings, using `insert_default`?
+    pub fn has_default(&self) -> bool {
+        self.constructor.is_some()
+    }
+
+    /// Insert a new entry with default settings onto the given (Perf UI root) entity.
+    ///
+    /// Returns `false` if the entry type does not support this.
+    pub fn insert_default(&self, commands: &mut EntityCommands) -> bool {
+        if let Some(constructor) = self.constructor {
+            constructor(commands);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Resource listing all Perf UI Entry types that have been registered with the app.
+///
+/// Useful for tooling, like a settings menu that lets the user
+/// pick which entries to display.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiEntryRegistry {
+    entries: Vec<PerfUiEntryInfo>,
+}
+
+impl PerfUiEntryRegistry {
+    /// Iterate over all the registered entry types, in the order they were registered.
+    pub fn entries(&self) -> impl Iterator<Item = &PerfUiEntryInfo> {
+        self.entries.iter()
+    }
+
+    /// Get the info about a specific entry type, if it has been registered.
+    pub fn get(&self, type_id: TypeId) -> Option<&PerfUiEntryInfo> {
+        self.entries.iter().find(|info| info.type_id == type_id)
+    }
+
+    pub(crate) fn register<T: PerfUiEntry>(&mut self) -> &mut PerfUiEntryInfo {
+        let type_id = TypeId::of::<T>();
+        let i = if let Some(i) = self.entries.iter().position(|info| info.type_id == type_id) {
+            i
+        } else {
+            self.entries.push(PerfUiEntryInfo {
+                type_id,
+                type_name: std::any::type_name::<T>(),
+                default_label: None,
+                constructor: None,
+            });
+            self.entries.len() - 1
+        };
+        &mut self.entries[i]
+    }
+
+    pub(crate) fn register_default<T: PerfUiEntry + Default>(&mut self) {
+        let info = self.register::<T>();
+        info.default_label = Some(T::default().label().to_owned());
+        info.constructor = Some(|commands| {
+            commands.insert(T::default());
+        });
+    }
+}
@@ -0,0 +1,790 @@
+//! Formatting of values for display.
+//!
+//! These functions do not depend on Bevy, only on the standard library's
+//! `core` and `alloc` parts (plus `unicode-segmentation`), so they can be
+//! used (and tested) on their own. They are also re-exported from
+//! [`utils`](crate::utils), for implementing new `PerfUiEntry` types.
+
+use core::time::Duration;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Format a float in a pretty way.
+///
+/// - Right aligned
+/// - Padded with spaces to accomodate total width (digits + precision + decimal point (if any))
+/// - Clamped to all 9s if above digits count (example: `99.999` if digits = 2 and precision = 3)
+/// - Like with [`format_pretty_int`], the `-` of negative values counts as a digit
+///   (example: `-9.999` if digits = 2 and precision = 3)
+/// - Infinity is displayed as `∞` and NaN as `NaN`, padded to the same width
+pub fn format_pretty_float(digits: u8, precision: u8, mut value: f64) -> String {
+    let digits = digits.max(1);
+    let max = 10.0f64.powi(digits as i32);
+    let max_negative = 10.0f64.powi(digits.max(2) as i32 - 1);
+
+    if !value.is_finite() {
+        let s = if value.is_nan() {
+            "NaN"
+        } else if value > 0.0 {
+            "∞"
+        } else {
+            "-∞"
+        };
+        return format!(
+            "{s:>width$}",
+            width = width_hint_pretty_float(digits, precision),
+        );
+    }
+
+    // compare the rounded value, so that 99.999 (at 2 digits
+    // of precision) is clamped too, and not displayed as "100.00"
+    let rounded: f64 = format!("{:.*}", precision as usize, value).parse().unwrap_or(value);
+    if rounded >= max {
+        value = max - 10.0f64.powi(-(precision as i32));
+    } else if rounded <= -max_negative {
+        value = -(max_negative - 10.0f64.powi(-(precision as i32)));
+    }
+
+    format!(
+        "{number:>width$.prec$}",
+        number = value,
+        width = width_hint_pretty_float(digits, precision),
+        prec = precision as usize,
+    )
+}
+
+/// Width hint for a value formatted with `format_pretty_float`
+pub fn width_hint_pretty_float(digits: u8, precision: u8) -> usize {
+    if precision > 0 {
+        digits as usize + precision as usize + 1
+    } else {
+        digits as usize
+    }
+}
+
+/// How many digits to display after the decimal point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precision {
+    /// Always display this many digits.
+    Fixed(u8),
+    /// Choose the number of digits for each value, so that it is
+    /// displayed with (roughly) this many significant figures.
+    ///
+    /// Large values are never rounded to fewer significant figures than
+    /// their integer part (example: `1420` with 2 sig figs).
+    Auto {
+        /// The number of significant figures to display.
+        sig_figs: u8,
+    },
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision::Fixed(2)
+    }
+}
+
+impl From<u8> for Precision {
+    fn from(precision: u8) -> Self {
+        Precision::Fixed(precision)
+    }
+}
+
+impl Precision {
+    /// The maximum number of digits after the decimal point that `Auto` will use.
+    pub const AUTO_MAX: u8 = 6;
+
+    /// Compute the number of digits after the decimal point to use for a value.
+    pub fn for_value(self, value: f64) -> u8 {
+        match self {
+            Precision::Fixed(precision) => precision,
+            Precision::Auto { sig_figs } => {
+                let sig_figs = sig_figs.max(1);
+                if value == 0.0 || !value.is_finite() {
+                    return (sig_figs - 1).min(Self::AUTO_MAX);
+                }
+                // Let the formatter round the value to the requested
+                // significant figures, so that values like 9.95 that round
+                // up to the next power of 10 get the correct exponent.
+                let sci = format!("{:.*e}", sig_figs as usize - 1, value);
+                let exp: i32 = sci.rsplit('e').next()
+                    .and_then(|e| e.parse().ok())
+                    .unwrap_or(0);
+                (sig_figs as i32 - 1 - exp).clamp(0, Self::AUTO_MAX as i32) as u8
+            }
+        }
+    }
+}
+
+/// How to display numbers that may be too large for their number of digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Notation {
+    /// Always display the full number.
+    ///
+    /// Values whose integer part does not fit in `digits` are clamped
+    /// to the largest value that fits (like `999.99`).
+    #[default]
+    Fixed,
+    /// Always display numbers with a metric suffix (like `1.23k`, `45.60M`).
+    Compact,
+    /// Display the full number if its integer part fits in `digits`.
+    /// Otherwise, switch to compact notation (like `Compact`).
+    ///
+    /// Common values stay precise, while rare huge values do not break the layout.
+    /// If the compact value is still too wide (with few `digits`), it is clamped
+    /// (like `Fixed`).
+    AutoCompact,
+}
+
+const COMPACT_SUFFIXES: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+
+/// Scale a (non-negative) value down for compact notation.
+///
+/// Returns the scaled value and the index of its suffix in `COMPACT_SUFFIXES`.
+fn compact_scale(precision: u8, value: f64) -> (f64, usize) {
+    let mut scaled = value;
+    let mut i = 0;
+    while i < COMPACT_SUFFIXES.len() - 1 {
+        // compare the rounded value, so that 999.996 (at 2 digits
+        // of precision) becomes "1.00k" and not "1000.00"
+        let rounded: f64 = format!("{:.*}", precision as usize, scaled).parse().unwrap_or(scaled);
+        if rounded < 1000.0 {
+            break;
+        }
+        scaled /= 1000.0;
+        i += 1;
+    }
+    (scaled, i)
+}
+
+/// Format a float in compact notation, with a metric suffix (like `1.23k`, `45.60M`).
+///
+/// `precision` is the number of digits after the decimal point, after scaling.
+/// Padded to the width from [`width_hint_pretty_float`]. Note that with
+/// fewer than 4 `digits`, large values may be wider than that.
+pub fn format_pretty_float_compact(digits: u8, precision: u8, value: f64) -> String {
+    if !value.is_finite() {
+        return format_pretty_float(digits, precision, value);
+    }
+    let (scaled, i) = compact_scale(precision, value.abs());
+    let sign = if value < 0.0 { "-" } else { "" };
+    format!(
+        "{s:>width$}",
+        s = format!("{}{:.*}{}", sign, precision as usize, scaled, COMPACT_SUFFIXES[i]),
+        width = width_hint_pretty_float(digits, precision),
+    )
+}
+
+/// Does the integer part of a value (rounded to `precision`) fit in `digits`?
+///
+/// As with `format_pretty_float`, the `-` of negative values counts as a digit.
+fn fits_in_digits(digits: u8, precision: u8, value: f64) -> bool {
+    let rounded = format!("{:.*}", precision as usize, value);
+    let int_len = rounded.split('.').next().unwrap_or_default().len();
+    int_len <= if value < 0.0 { digits.max(2) } else { digits.max(1) } as usize
+}
+
+/// Format a float in a pretty way, with a configurable [`Precision`] and [`Notation`].
+///
+/// Padded to the width from [`width_hint_pretty_float_precision`], except
+/// for long values with `Notation::Compact` (see [`format_pretty_float_compact`]).
+pub fn format_pretty_float_notation(digits: u8, precision: Precision, notation: Notation, value: f64) -> String {
+    let compact = match notation {
+        Notation::Fixed => false,
+        Notation::Compact => true,
+        Notation::AutoCompact => value.is_finite()
+            && !fits_in_digits(digits, precision.for_value(value), value),
+    };
+    let width = width_hint_pretty_float_precision(digits, precision);
+    if compact {
+        let (scaled, _) = compact_scale(precision.for_value(value), value.abs());
+        let s = format_pretty_float_compact(digits, precision.for_value(scaled), value);
+        let s = s.trim_start();
+        // with few digits, scaling may not shrink the value enough
+        // (like "-999.00"), so clamp it instead, to keep the layout
+        if notation != Notation::AutoCompact || s.len() <= width {
+            return format!("{s:>width$}");
+        }
+    }
+    format_pretty_float_precision(digits, precision, value)
+}
+
+/// Format a float with all the number formatting options of the built-in entries.
+///
+/// Like [`format_pretty_float_notation`], then the trailing zeros are
+/// optionally removed (see [`trim_trailing_zeros`]), and the padding is
+/// replaced with `pad_char` (see [`pad_pretty_number`]).
+pub fn format_pretty_float_options(
+    digits: u8,
+    precision: Precision,
+    notation: Notation,
+    trim_zeros: bool,
+    pad_char: char,
+    value: f64,
+) -> String {
+    let mut s = format_pretty_float_notation(digits, precision, notation, value);
+    if trim_zeros {
+        s = trim_trailing_zeros(&s);
+    }
+    pad_pretty_number(&s, pad_char)
+}
+
+/// Remove trailing zeros (and a dangling decimal point) from a formatted number.
+///
+/// For example, with a precision of 3, `5.000` becomes `5` and `5.200` becomes `5.2`.
+/// The result is padded on the left, to keep the same width as the input,
+/// so that columns of right-aligned values stay aligned. Any suffix after
+/// the number (like the `k` from compact notation) is kept. Integers are
+/// left unchanged.
+pub fn trim_trailing_zeros(s: &str) -> String {
+    let end = s.rfind(|c: char| c.is_ascii_digit())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let (number, suffix) = s.split_at(end);
+    if !number.contains('.') {
+        return s.to_owned();
+    }
+    let mut trimmed = number.trim_end_matches('0').trim_end_matches('.');
+    // a tiny negative number rounded to zero
+    if trimmed.trim_start() == "-0" {
+        trimmed = "0";
+    }
+    format!(
+        "{:>width$}",
+        format!("{trimmed}{suffix}"),
+        width = s.chars().count(),
+    )
+}
+
+/// Format a float in a pretty way, with a configurable [`Precision`].
+///
+/// Like [`format_pretty_float`], but the number of digits after
+/// the decimal point may be chosen for each value. Always padded to
+/// the width from [`width_hint_pretty_float_precision`].
+pub fn format_pretty_float_precision(digits: u8, precision: Precision, value: f64) -> String {
+    let s = format_pretty_float(digits, precision.for_value(value), value);
+    format!(
+        "{s:>width$}",
+        s = s.trim_start(),
+        width = width_hint_pretty_float_precision(digits, precision),
+    )
+}
+
+/// Width hint for a value formatted with `format_pretty_float_precision`
+pub fn width_hint_pretty_float_precision(digits: u8, precision: Precision) -> usize {
+    match precision {
+        Precision::Fixed(precision) => width_hint_pretty_float(digits, precision),
+        Precision::Auto { sig_figs } => {
+            // either all the integer digits + the remaining sig figs,
+            // or "0." + the max number of fractional digits
+            (digits.max(1) as usize + sig_figs.max(1) as usize)
+                .max(2 + Precision::AUTO_MAX as usize)
+        }
+    }
+}
+
+/// Format an integer in a pretty way.
+///
+/// - Right aligned
+/// - Padded with spaces to accomodate total width (digits)
+/// - Clamped to all 9s if above digits count (example: `99` if digits = 2)
+pub fn format_pretty_int(digits: u8, mut value: i64) -> String {
+    if value < 0 {
+        let digits = digits.max(2) - 1;
+        let max = 10i64.pow(digits as u32);
+        if -value >= max {
+            value = -(max - 1);
+        }
+    } else {
+        let digits = digits.max(1);
+        let max = 10i64.pow(digits as u32);
+        if value >= max {
+            value = max - 1;
+        }
+    };
+
+    format!(
+        "{number:>width$}",
+        number = value,
+        width = width_hint_pretty_int(digits),
+    )
+}
+
+/// Format an integer in a pretty way, padded with `pad_char` instead of spaces.
+///
+/// Like [`format_pretty_int`], but the integer part is padded to `digits`
+/// using `pad_char` (like `00042` with `'0'`). The sign column is kept: it is
+/// a space for non-negative values, and the `-` comes before the padding
+/// (like `-0042`). The width is the same as with [`format_pretty_int`].
+pub fn format_pretty_int_padded(digits: u8, pad_char: char, value: i64) -> String {
+    let s = format_pretty_int(digits, value);
+    match s.strip_prefix(' ') {
+        // keep the (empty) sign column
+        Some(rest) if value >= 0 => format!(" {}", pad_pretty_number(rest, pad_char)),
+        _ => pad_pretty_number(&s, pad_char),
+    }
+}
+
+/// Replace the spaces padding a formatted number with `pad_char`.
+///
+/// Works on the output of the other functions in this module, like
+/// [`format_pretty_float`]. The `-` of negative numbers is moved before
+/// the padding (like `-005.20` with `'0'`). Text that is not a number
+/// (like `NaN` or `∞`) is left padded with spaces.
+pub fn pad_pretty_number(s: &str, pad_char: char) -> String {
+    if pad_char == ' ' {
+        return s.to_owned();
+    }
+    let number = s.trim_start_matches(' ');
+    let n_pad = s.len() - number.len();
+    let (sign, rest) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return s.to_owned();
+    }
+    let mut padded = String::with_capacity(s.len() + n_pad * pad_char.len_utf8());
+    padded.push_str(sign);
+    padded.extend(core::iter::repeat_n(pad_char, n_pad));
+    padded.push_str(rest);
+    padded
+}
+
+/// Format a signed change of an integer value, like "+12" or "-3".
+///
+/// `None` (no previous value) is displayed as "--". The result is padded
+/// to the same width as [`format_pretty_int`] (see [`width_hint_pretty_int`]).
+pub fn format_pretty_delta(digits: u8, value: Option<i64>) -> String {
+    let width = width_hint_pretty_int(digits);
+    let Some(value) = value else {
+        return format!("{:>width$}", "--");
+    };
+    let max = 10i64.pow(digits.max(1) as u32) - 1;
+    format!("{:>+width$}", value.clamp(-max, max))
+}
+
+/// Width hint for a value formatted with `format_pretty_int`
+pub fn width_hint_pretty_int(digits: u8) -> usize {
+    digits as usize + 1
+}
+
+const BYTES_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Format an amount of memory in a pretty way, using binary units (like `12.34 MiB`).
+///
+/// - Right aligned, padded to the width from [`width_hint_pretty_bytes`]
+/// - Uses the largest unit that keeps the number at or above 1
+/// - Amounts below 1 KiB are displayed without a fractional part (like `512 B`)
+pub fn format_pretty_bytes(precision: u8, bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut i = 0;
+    while value >= 1024.0 && i < BYTES_UNITS.len() - 1 {
+        value /= 1024.0;
+        i += 1;
+    }
+    let s = if i == 0 {
+        format!("{} {}", bytes, BYTES_UNITS[0])
+    } else {
+        format!("{:.*} {}", precision as usize, value, BYTES_UNITS[i])
+    };
+    format!("{s:>width$}", width = width_hint_pretty_bytes(precision))
+}
+
+/// Width hint for a value formatted with `format_pretty_bytes`
+pub fn width_hint_pretty_bytes(precision: u8) -> usize {
+    // "1023.99 KiB"
+    width_hint_pretty_float(4, precision) + 4
+}
+
+/// Format a time duration in a pretty way.
+///
+/// See [`format_pretty_time_hms`].
+///
+/// - Clamped to all 9s if above max (example: `99:59:59.999`)
+pub fn format_pretty_time(precision: u8, value: Duration) -> String {
+    let precision = precision.min(9);
+    let max = 99 * 3600 + 59 * 60 + 59;
+    let secs = value.as_secs();
+    if secs > max {
+        if precision > 0 {
+            return format!("99:59:59.{dummy:9<prec$}", dummy = "", prec = precision as usize);
+        } else {
+            return "99:59:59".into()
+        }
+    }
+    let secs = secs as u32;
+    format_pretty_time_hms(precision, secs / 3600, secs / 60, secs, value.subsec_nanos())
+}
+
+/// Format time (provided as hours, minutes, seconds, nanoseconds) in a pretty way.
+///
+/// - Right aligned
+/// - `HH:MM:SS.f*` (hours, minutes, seconds, fractional seconds)
+/// - Fractional part optional (precision = 0 to disable, no decimal point is displayed)
+/// - Precision is limited to 9 digits (nanoseconds)
+/// - Automatically omits hours and minutes if they would be zero
+/// - Padded with spaces to accomodate maximum width
+pub fn format_pretty_time_hms(precision: u8, h: u32, m: u32, s: u32, nanos: u32) -> String {
+    // sanitize
+    let precision = precision.min(9);
+    let hrs = h % 100;
+    let mins = m % 60;
+    let secs = s % 60;
+    let frac = nanos.min(999_999_999) / 10u32.pow(9 - precision as u32);
+    if precision > 0 {
+        if hrs > 0 {
+            format!("{:2}:{:02}:{:02}.{:0w$}", hrs, mins, secs, frac, w = precision as usize)
+        } else if mins > 0 {
+            format!("{:5}:{:02}.{:0w$}", mins, secs, frac, w = precision as usize)
+        } else {
+            format!("{:8}.{:0w$}", secs, frac, w = precision as usize)
+        }
+    } else {
+        if hrs > 0 {
+            format!("{:2}:{:02}:{:02}", hrs, mins, secs)
+        } else if mins > 0 {
+            format!("{:5}:{:02}", mins, secs)
+        } else {
+            format!("{:8}", secs)
+        }
+    }
+}
+
+/// Width hint for a value formatted with `format_pretty_time`/`format_pretty_time_hms`
+pub fn width_hint_pretty_time(precision: u8) -> usize {
+    if precision > 0 {
+        // "HH:MM:SS.f+"
+        9 + precision.min(9) as usize
+    } else {
+        8
+    }
+}
+
+/// Format a time duration, picking the most readable unit for its magnitude.
+///
+/// - Below 1 second: whole milliseconds (example: `950 ms`)
+/// - Below 60 seconds: seconds, with `precision` fractional digits (example: `12.3 s`)
+/// - From 60 seconds: `M:SS` or `H:MM:SS`, without fractional part (example: `4:05`)
+/// - Right aligned, padded to the width from [`width_hint_pretty_duration_adaptive`]
+pub fn format_pretty_duration_adaptive(precision: u8, value: Duration) -> String {
+    let precision = precision.min(9);
+    let width = width_hint_pretty_duration_adaptive(precision);
+    let s = if value < Duration::from_secs(1) {
+        format!("{} ms", value.as_millis())
+    } else if value < Duration::from_secs(60) {
+        // truncate instead of rounding, so that we never display "60.0 s"
+        let scale = 10u64.pow(precision as u32);
+        let secs = (value.as_secs_f64() * scale as f64).floor() / scale as f64;
+        format!("{:.p$} s", secs, p = precision as usize)
+    } else {
+        format_pretty_time(0, value).trim_start().to_owned()
+    };
+    format!("{s:>width$}")
+}
+
+/// Width hint for a value formatted with `format_pretty_duration_adaptive`
+pub fn width_hint_pretty_duration_adaptive(precision: u8) -> usize {
+    // "999 ms", "59.f+ s", "HH:MM:SS"
+    let secs = if precision > 0 {
+        5 + precision.min(9) as usize
+    } else {
+        4
+    };
+    secs.max(8)
+}
+
+/// Truncate a string to at most `max_chars` characters, ending it with "…" if it was cut.
+///
+/// Characters are counted as grapheme clusters, so that multibyte
+/// characters (and combining sequences) are never cut half-way.
+/// The ellipsis counts towards the limit.
+pub fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
+    if s.graphemes(true).nth(max_chars).is_none() {
+        return s.to_owned();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+    let mut r: String = s.graphemes(true).take(max_chars - 1).collect();
+    r.push('…');
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_precision() {
+        let t = Duration::new(83, 456_000_000);
+        assert_eq!(format_pretty_time(0, t), "    1:23");
+        assert_eq!(format_pretty_time(1, t), "    1:23.4");
+        assert_eq!(format_pretty_time(3, t), "    1:23.456");
+        let t = Duration::new(3723, 250_000_000);
+        assert_eq!(format_pretty_time(0, t), " 1:02:03");
+        assert_eq!(format_pretty_time(1, t), " 1:02:03.2");
+        assert_eq!(format_pretty_time(3, t), " 1:02:03.250");
+        assert_eq!(format_pretty_time_hms(0, 0, 0, 5, 0), "       5");
+        assert_eq!(format_pretty_time_hms(3, 0, 0, 5, 7_000_000), "       5.007");
+        for precision in [0, 1, 3] {
+            assert_eq!(format_pretty_time(precision, t).len(), width_hint_pretty_time(precision));
+        }
+    }
+
+    #[test]
+    fn float_non_finite() {
+        assert_eq!(format_pretty_float(4, 2, f64::NAN), "    NaN");
+        assert_eq!(format_pretty_float(4, 2, f64::INFINITY), "      ∞");
+        assert_eq!(format_pretty_float(4, 2, f64::NEG_INFINITY), "     -∞");
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            for precision in [0, 2] {
+                let s = format_pretty_float(4, precision, value);
+                assert_eq!(s.chars().count(), width_hint_pretty_float(4, precision));
+            }
+        }
+    }
+
+    #[test]
+    fn auto_precision_boundaries() {
+        let auto = |sig_figs| Precision::Auto { sig_figs };
+        // 9.95 is slightly less than that in binary, so it rounds down
+        assert_eq!(auto(2).for_value(9.95), 1);
+        assert_eq!(format_pretty_float_precision(4, auto(2), 9.95).trim(), "9.9");
+        // values that round up to the next power of 10 lose a decimal
+        assert_eq!(auto(2).for_value(9.96), 0);
+        assert_eq!(format_pretty_float_precision(4, auto(2), 9.96).trim(), "10");
+        assert_eq!(format_pretty_float_precision(4, auto(2), -9.96).trim(), "-10");
+        assert_eq!(format_pretty_float_precision(4, auto(3), 9.95).trim(), "9.95");
+        // 99.5 is exact, and rounds up
+        assert_eq!(format_pretty_float_precision(4, auto(2), 99.4).trim(), "99");
+        assert_eq!(format_pretty_float_precision(4, auto(2), 99.5).trim(), "100");
+        assert_eq!(format_pretty_float_precision(4, auto(3), 99.5).trim(), "99.5");
+        assert_eq!(format_pretty_float_precision(4, auto(3), 99.95).trim(), "100");
+        // small and large values
+        assert_eq!(format_pretty_float_precision(4, auto(2), 0.042).trim(), "0.042");
+        assert_eq!(format_pretty_float_precision(4, auto(2), 1420.0).trim(), "1420");
+        assert_eq!(format_pretty_float_precision(4, auto(2), 0.0).trim(), "0.0");
+        assert_eq!(auto(2).for_value(1e-12), Precision::AUTO_MAX);
+        // always padded to the same width
+        for value in [0.042, 9.95, 99.5, 1420.0] {
+            assert_eq!(
+                format_pretty_float_precision(4, auto(2), value).len(),
+                width_hint_pretty_float_precision(4, auto(2)),
+            );
+        }
+    }
+
+    #[test]
+    fn duration_adaptive_transitions() {
+        let ms = Duration::from_millis;
+        assert_eq!(format_pretty_duration_adaptive(1, ms(999)).trim(), "999 ms");
+        assert_eq!(format_pretty_duration_adaptive(1, ms(1000)).trim(), "1.0 s");
+        assert_eq!(format_pretty_duration_adaptive(0, ms(1000)).trim(), "1 s");
+        // truncated, never rounded up to "60.0 s"
+        assert_eq!(format_pretty_duration_adaptive(1, ms(59_999)).trim(), "59.9 s");
+        assert_eq!(format_pretty_duration_adaptive(1, ms(60_000)).trim(), "1:00");
+        assert_eq!(format_pretty_duration_adaptive(1, ms(60_500)).trim(), "1:00");
+        assert_eq!(format_pretty_duration_adaptive(1, Duration::from_secs(3600)).trim(), "1:00:00");
+        assert_eq!(format_pretty_duration_adaptive(1, Duration::ZERO).trim(), "0 ms");
+        for value in [ms(999), ms(1000), ms(59_999), ms(60_000)] {
+            assert_eq!(
+                format_pretty_duration_adaptive(1, value).len(),
+                width_hint_pretty_duration_adaptive(1),
+            );
+        }
+    }
+
+    #[test]
+    fn auto_compact_switchover() {
+        let auto_compact = |digits, value| {
+            format_pretty_float_notation(digits, Precision::Fixed(2), Notation::AutoCompact, value)
+        };
+        // the largest value that fits in 3 digits stays fixed…
+        assert_eq!(auto_compact(3, 999.99), "999.99");
+        // …but not if it rounds up to 4 digits
+        assert_eq!(auto_compact(3, 999.996), " 1.00k");
+        assert_eq!(auto_compact(3, 1000.0), " 1.00k");
+        // negative values that compact notation cannot shrink are clamped
+        assert_eq!(auto_compact(3, -999.0), "-99.99");
+        assert_eq!(auto_compact(3, -1000.0), "-1.00k");
+        // as are values too large for compact notation with 3 digits
+        assert_eq!(auto_compact(3, 123456.0), "999.99");
+        assert_eq!(auto_compact(2, 150.0), "99.99");
+        for digits in 2..=5 {
+            for value in [0.0, 99.5, -99.5, 999.0, -999.0, 1000.0, -1000.0, 123456.0, -123456.0, 1e20] {
+                let width = width_hint_pretty_float_precision(digits, Precision::Fixed(2));
+                assert_eq!(auto_compact(digits, value).len(), width, "{digits} {value}");
+            }
+        }
+        // with one more digit, the switchover is one magnitude later
+        assert_eq!(auto_compact(4, 9999.0), "9999.00");
+        assert_eq!(auto_compact(4, 10000.0), " 10.00k");
+        // `Compact` always switches, `Fixed` never does
+        assert_eq!(format_pretty_float_notation(4, Precision::Fixed(2), Notation::Compact, 999.0), " 999.00");
+        assert_eq!(format_pretty_float_notation(4, Precision::Fixed(2), Notation::Compact, 1500.0), "  1.50k");
+        assert_eq!(format_pretty_float_notation(3, Precision::Fixed(2), Notation::Fixed, 1500.0), "999.99");
+    }
+
+    #[test]
+    fn float() {
+        // precision
+        assert_eq!(format_pretty_float(2, 0, 12.345), "12");
+        assert_eq!(format_pretty_float(2, 2, 12.345), "12.35");
+        assert_eq!(format_pretty_float(2, 3, 1.5), " 1.500");
+        // digits
+        assert_eq!(format_pretty_float(4, 2, 12.345), "  12.35");
+        assert_eq!(format_pretty_float(0, 1, 5.0), "5.0");
+        // negative
+        assert_eq!(format_pretty_float(2, 2, -1.5), "-1.50");
+        assert_eq!(format_pretty_float(4, 1, -12.34), " -12.3");
+        // zero
+        assert_eq!(format_pretty_float(2, 2, 0.0), " 0.00");
+        assert_eq!(format_pretty_float(3, 0, 0.0), "  0");
+        // overflow
+        assert_eq!(format_pretty_float(2, 2, 100.0), "99.99");
+        assert_eq!(format_pretty_float(2, 2, 123456.0), "99.99");
+        assert_eq!(format_pretty_float(2, 2, 99.999), "99.99");
+        assert_eq!(format_pretty_float(3, 0, 99.5), "100");
+        assert_eq!(format_pretty_float(2, 0, 99.5), "99");
+        assert_eq!(format_pretty_float(2, 2, -99.5), "-9.99");
+        assert_eq!(format_pretty_float(3, 0, -123456.0), "-99");
+        assert_eq!(format_pretty_float(3, 0, -99.5), "-99");
+        for value in [0.0, -1.5, 12.345, 99.999, -123456.0, 123456.0] {
+            assert_eq!(format_pretty_float(2, 2, value).len(), width_hint_pretty_float(2, 2));
+        }
+    }
+
+    #[test]
+    fn float_compact() {
+        assert_eq!(format_pretty_float_compact(4, 2, 0.0), "   0.00");
+        assert_eq!(format_pretty_float_compact(4, 2, 999.0), " 999.00");
+        assert_eq!(format_pretty_float_compact(4, 2, 1500.0), "  1.50k");
+        assert_eq!(format_pretty_float_compact(4, 0, 1500.0), "  2k");
+        assert_eq!(format_pretty_float_compact(4, 2, -1500.0), " -1.50k");
+        // rounding up to the next suffix
+        assert_eq!(format_pretty_float_compact(4, 2, 999_999.0), "  1.00M");
+        assert_eq!(format_pretty_float_compact(4, 2, 2.5e18), "  2.50E");
+    }
+
+    #[test]
+    fn int() {
+        // digits
+        assert_eq!(format_pretty_int(2, 5), "  5");
+        assert_eq!(format_pretty_int(4, 1234), " 1234");
+        assert_eq!(format_pretty_int(0, 7), "7");
+        // negative
+        assert_eq!(format_pretty_int(4, -123), " -123");
+        assert_eq!(format_pretty_int(2, -5), " -5");
+        // zero
+        assert_eq!(format_pretty_int(2, 0), "  0");
+        // overflow
+        assert_eq!(format_pretty_int(2, 100), " 99");
+        assert_eq!(format_pretty_int(2, i64::MAX), " 99");
+        assert_eq!(format_pretty_int(4, -12345), " -999");
+        assert_eq!(format_pretty_int(2, i64::MIN + 1), " -9");
+        for value in [0, 5, -5, 100, -12345] {
+            assert_eq!(format_pretty_int(4, value).len(), width_hint_pretty_int(4));
+        }
+    }
+
+    #[test]
+    fn delta() {
+        assert_eq!(format_pretty_delta(3, None), "  --");
+        assert_eq!(format_pretty_delta(3, Some(0)), "  +0");
+        assert_eq!(format_pretty_delta(3, Some(12)), " +12");
+        assert_eq!(format_pretty_delta(3, Some(-3)), "  -3");
+        assert_eq!(format_pretty_delta(3, Some(1000)), "+999");
+        assert_eq!(format_pretty_delta(3, Some(-1000)), "-999");
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_pretty_bytes(2, 0), "        0 B");
+        assert_eq!(format_pretty_bytes(2, 1023), "     1023 B");
+        assert_eq!(format_pretty_bytes(2, 1024), "   1.00 KiB");
+        assert_eq!(format_pretty_bytes(1, 1536), "   1.5 KiB");
+        assert_eq!(format_pretty_bytes(2, 5 * 1024 * 1024), "   5.00 MiB");
+        assert_eq!(format_pretty_bytes(2, u64::MAX), "  16.00 EiB");
+        for bytes in [0, 1023, 1024, u64::MAX] {
+            assert_eq!(format_pretty_bytes(2, bytes).len(), width_hint_pretty_bytes(2));
+        }
+    }
+
+    #[test]
+    fn time() {
+        // zero
+        assert_eq!(format_pretty_time(0, Duration::ZERO), "       0");
+        assert_eq!(format_pretty_time(3, Duration::ZERO), "       0.000");
+        // digits are dropped when zero
+        assert_eq!(format_pretty_time(2, Duration::from_millis(5_250)), "       5.25");
+        assert_eq!(format_pretty_time(2, Duration::from_millis(65_250)), "    1:05.25");
+        // fractions are truncated, not rounded
+        assert_eq!(format_pretty_time(1, Duration::from_millis(5_999)), "       5.9");
+        // precision is limited to nanoseconds
+        assert_eq!(format_pretty_time(12, Duration::new(1, 5)), "       1.000000005");
+        // overflow
+        let max = Duration::from_secs(100 * 3600);
+        assert_eq!(format_pretty_time(0, max), "99:59:59");
+        assert_eq!(format_pretty_time(3, max), "99:59:59.999");
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncate_with_ellipsis("Frame Time", 20), "Frame Time");
+        assert_eq!(truncate_with_ellipsis("Frame Time", 10), "Frame Time");
+        assert_eq!(truncate_with_ellipsis("Frame Time", 6), "Frame…");
+        assert_eq!(truncate_with_ellipsis("Frame Time", 0), "");
+        // never cut multibyte characters
+        assert_eq!(truncate_with_ellipsis("Größe", 4), "Grö…");
+        assert_eq!(truncate_with_ellipsis("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+    }
+
+    #[test]
+    fn trim_zeros() {
+        let trimmed = |value| trim_trailing_zeros(&format_pretty_float(4, 3, value));
+        assert_eq!(trimmed(5.0), "       5");
+        assert_eq!(trimmed(5.20), "     5.2");
+        assert_eq!(trimmed(5.25), "    5.25");
+        assert_eq!(trimmed(-5.0), "      -5");
+        assert_eq!(trimmed(0.0), "       0");
+        // not "-0"
+        assert_eq!(trimmed(-0.0001), "       0");
+        // the width is kept, so columns stay aligned
+        for value in [5.0, 5.20, 5.25] {
+            assert_eq!(trimmed(value).len(), width_hint_pretty_float(4, 3));
+        }
+        // suffixes are kept, integers are unchanged
+        assert_eq!(trim_trailing_zeros(&format_pretty_float_compact(4, 2, 1500.0)), "   1.5k");
+        assert_eq!(trim_trailing_zeros(" 100"), " 100");
+    }
+
+    #[test]
+    fn padding() {
+        // integers, at various digit counts
+        assert_eq!(format_pretty_int_padded(3, '0', 42), " 042");
+        assert_eq!(format_pretty_int_padded(5, '0', 42), " 00042");
+        assert_eq!(format_pretty_int_padded(1, '0', 7), " 7");
+        assert_eq!(format_pretty_int_padded(2, '0', 0), " 00");
+        assert_eq!(format_pretty_int_padded(3, '0', 12345), " 999");
+        // the sign comes before the zeros
+        assert_eq!(format_pretty_int_padded(5, '0', -42), "-00042");
+        assert_eq!(format_pretty_int_padded(3, '0', -12345), "-099");
+        // spaces are the same as without padding
+        for (digits, value) in [(3, 42), (5, 42), (5, -42), (1, 7)] {
+            assert_eq!(format_pretty_int_padded(digits, ' ', value), format_pretty_int(digits, value));
+            assert_eq!(format_pretty_int_padded(digits, '0', value).len(), width_hint_pretty_int(digits));
+        }
+        // floats
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, 5.2), '0'), "005.20");
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, -5.2), '0'), "-05.20");
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, 0.0), '0'), "000.00");
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, 5.2), ' '), "  5.20");
+        assert_eq!(pad_pretty_number("  5.2", '·'), "··5.2");
+        // not numbers
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, f64::NAN), '0'), "   NaN");
+        assert_eq!(pad_pretty_number(&format_pretty_float(3, 2, f64::NEG_INFINITY), '0'), "    -∞");
+    }
+}
@@ -0,0 +1,228 @@
+//! Saving and loading Perf UI layouts.
+//!
+//! A layout is the configuration of one Perf UI: the settings of the
+//! [`PerfUiRoot`] and of all the built-in entries on it. It can be saved
+//! as a RON string, and later loaded to recreate the same Perf UI.
+//!
+//! ```rust
+//! fn save_layout(world: &mut World) {
+//!     let mut q = world.query_filtered::<Entity, With<PerfUiRoot>>();
+//!     let e_root = q.single(world);
+//!     let ron = save_perf_ui_layout(world, e_root).unwrap();
+//!     std::fs::write("perf_ui.ron", ron).unwrap();
+//! }
+//!
+//! fn load_layout(mut commands: Commands) {
+//!     let ron = std::fs::read_to_string("perf_ui.ron").unwrap();
+//!     load_perf_ui_layout(&mut commands.spawn_empty(), &ron).unwrap();
+//! }
+//! ```
+//!
+//! Only the built-in entries listed in [`PerfUiEntryConfig`] are saved.
+//! Custom entries, widgets (such as bars), and entries that hold runtime
+//! state (such as [`PerfUiEntryChannel`](crate::entries::misc::PerfUiEntryChannel))
+//! are ignored. Fonts are not saved either, as they are asset handles.
+
+use bevy::prelude::*;
+use bevy::ecs::world::EntityRef;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::entry::PerfUiEntry;
+
+/// A saved Perf UI layout.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PerfUiLayout {
+    /// The settings of the Perf UI itself.
+    pub root: PerfUiRoot,
+    /// All the entries to display.
+    pub entries: Vec<PerfUiEntryConfig>,
+}
+
+impl PerfUiLayout {
+    /// Get the layout of an existing Perf UI entity.
+    ///
+    /// Returns `None` if the entity has no `PerfUiRoot`.
+    pub fn from_entity(entity: EntityRef) -> Option<Self> {
+        Some(PerfUiLayout {
+            root: entity.get::<PerfUiRoot>()?.clone(),
+            entries: PerfUiEntryConfig::collect(entity),
+        })
+    }
+
+    /// Insert the root and all the entries onto an entity.
+    pub fn insert(self, commands: &mut EntityCommands) {
+        commands.insert(self.root);
+        for entry in self.entries {
+            entry.insert(commands);
+        }
+    }
+}
+
+/// Error from saving or loading a Perf UI layout.
+#[derive(Debug)]
+pub enum PerfUiLayoutError {
+    /// The entity does not exist or has no `PerfUiRoot`.
+    NotAPerfUi(Entity),
+    /// The layout could not be converted to RON.
+    Serialize(ron::Error),
+    /// The RON string is not a valid layout.
+    Deserialize(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for PerfUiLayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PerfUiLayoutError::NotAPerfUi(e) => write!(f, "entity {} is not a Perf UI", e),
+            PerfUiLayoutError::Serialize(e) => write!(f, "cannot serialize Perf UI layout: {}", e),
+            PerfUiLayoutError::Deserialize(e) => write!(f, "cannot deserialize Perf UI layout: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PerfUiLayoutError {}
+
+/// Save the layout of the Perf UI on the given entity as a RON string.
+pub fn save_perf_ui_layout(world: &World, e_root: Entity) -> Result<String, PerfUiLayoutError> {
+    let layout = world.get_entity(e_root).ok()
+        .and_then(PerfUiLayout::from_entity)
+        .ok_or(PerfUiLayoutError::NotAPerfUi(e_root))?;
+    ron::ser::to_string_pretty(&layout, ron::ser::PrettyConfig::default())
+        .map_err(PerfUiLayoutError::Serialize)
+}
+
+/// Load a layout from a RON string, and insert it onto the given entity.
+pub fn load_perf_ui_layout(commands: &mut EntityCommands, ron: &str) -> Result<(), PerfUiLayoutError> {
+    let layout: PerfUiLayout = ron::from_str(ron)
+        .map_err(PerfUiLayoutError::Deserialize)?;
+    layout.insert(commands);
+    Ok(())
+}
+
+macro_rules! perf_ui_entry_configs {
+    ($( $(#[$meta:meta])* $variant:ident($ty:ty), )*) => {
+        /// The configuration of any built-in Perf UI Entry, tagged with its type.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[allow(missing_docs)]
+        pub enum PerfUiEntryConfig {
+            $( $(#[$meta])* $variant($ty), )*
+        }
+
+        impl PerfUiEntryConfig {
+            /// Get the configs of all supported entries on an entity.
+            ///
+            /// They are returned in the order in which they are displayed.
+            pub fn collect(entity: EntityRef) -> Vec<Self> {
+                let mut configs = Vec::new();
+                $(
+                    $(#[$meta])*
+                    if let Some(entry) = entity.get::<$ty>() {
+                        configs.push(PerfUiEntryConfig::$variant(entry.clone()));
+                    }
+                )*
+                configs.sort_by_key(|config| config.sort_key());
+                configs
+            }
+
+            /// Insert the entry component onto an entity.
+            pub fn insert(self, commands: &mut EntityCommands) {
+                match self {
+                    $( $(#[$meta])* PerfUiEntryConfig::$variant(entry) => {
+                        commands.insert(entry);
+                    } )*
+                }
+            }
+
+            /// The sort key of the entry.
+            pub fn sort_key(&self) -> i32 {
+                match self {
+                    $( $(#[$meta])* PerfUiEntryConfig::$variant(entry) => {
+                        PerfUiEntry::sort_key(entry)
+                    } )*
+                }
+            }
+        }
+    };
+}
+
+perf_ui_entry_configs! {
+    Fps(PerfUiEntryFPS),
+    FpsWorst(PerfUiEntryFPSWorst),
+    FrameTime(PerfUiEntryFrameTime),
+    FrameTimeWorst(PerfUiEntryFrameTimeWorst),
+    GpuFrameTime(PerfUiEntryGpuFrameTime),
+    FrameBudget(PerfUiEntryFrameBudget),
+    Diagnostic(PerfUiEntryDiagnostic),
+    Span(PerfUiEntrySpan),
+    FrameCount(PerfUiEntryFrameCount),
+    EntityCount(PerfUiEntryEntityCount),
+    Allocations(PerfUiEntryAllocations),
+    #[cfg(feature = "sysinfo")]
+    CpuUsage(PerfUiEntryCpuUsage),
+    #[cfg(feature = "sysinfo")]
+    MemUsage(PerfUiEntryMemUsage),
+    #[cfg(feature = "sysinfo")]
+    CpuFrequency(PerfUiEntryCpuFrequency),
+    AssetsLoading(PerfUiEntryAssetsLoading),
+    ImageMemory(PerfUiEntryAssetMemory<Image>),
+    MeshMemory(PerfUiEntryAssetMemory<Mesh>),
+    InputLatency(PerfUiEntryInputLatency),
+    GamepadCount(PerfUiEntryGamepadCount),
+    Static(PerfUiEntryStatic),
+    Version(PerfUiEntryVersion),
+    RenderBackend(PerfUiEntryRenderBackend),
+    Manual(PerfUiEntryManual),
+    Record(PerfUiEntryRecord),
+    LastSpike(PerfUiEntryLastSpike),
+    Reflect(PerfUiEntryReflect),
+    #[cfg(feature = "audio")]
+    AudioLatency(PerfUiEntryAudioLatency),
+    #[cfg(feature = "animation")]
+    ActiveAnimations(PerfUiEntryActiveAnimations),
+    Clock(PerfUiEntryClock),
+    RunningTime(PerfUiEntryRunningTime),
+    TimeSince(PerfUiEntryTimeSince),
+    LapTimer(PerfUiEntryLapTimer),
+    Interval(PerfUiEntryInterval),
+    FixedTimeStep(PerfUiEntryFixedTimeStep),
+    FixedOverstep(PerfUiEntryFixedOverstep),
+    FixedSteps(PerfUiEntryFixedSteps),
+    WindowResolution(PerfUiEntryWindowResolution),
+    WindowScaleFactor(PerfUiEntryWindowScaleFactor),
+    RefreshRate(PerfUiEntryRefreshRate),
+    WindowCount(PerfUiEntryWindowCount),
+    WindowMode(PerfUiEntryWindowMode),
+    WindowPresentMode(PerfUiEntryWindowPresentMode),
+    CursorPosition(PerfUiEntryCursorPosition),
+}
+
+pub(crate) mod serde_global_z_index {
+    use bevy::prelude::GlobalZIndex;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<GlobalZIndex>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.map(|z| z.0).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<GlobalZIndex>, D::Error> {
+        Ok(Option::<i32>::deserialize(deserializer)?.map(GlobalZIndex))
+    }
+}
+
+pub(crate) mod serde_diagnostic_path {
+    use bevy::diagnostic::DiagnosticPath;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &DiagnosticPath, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DiagnosticPath, D::Error> {
+        let path = String::deserialize(deserializer)?;
+        if path.is_empty() || path.starts_with('/') || path.ends_with('/') || path.contains("//") {
+            return Err(serde::de::Error::custom(format!("invalid diagnostic path: {:?}", path)));
+        }
+        Ok(DiagnosticPath::new(path))
+    }
+}
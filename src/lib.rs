@@ -9,9 +9,10 @@
 //!  - Modular! You decide what info you want to display!
 //!    - Choose any combination of predefined entries
 //!      (see [`specific_entries`](https://github.com/IyesGames/iyes_perf_ui/blob/v0.2.3/examples/specific_entries.rs) example):
-//!      - Framerate (FPS), Frame Time, Frame Count, ECS Entity Count, CPU Usage, RAM Usage,
-//!        Wall Clock, Running Time, Fixed Time Step, Fixed Overstep,
-//!        Cursor Position, Window Resolution, Window Scale Factor, Window Mode, Present Mode
+//!      - Framerate (FPS), Frame Time, GPU Frame Time, Frame Budget, Frame Count, ECS Entity Count, Allocations per Frame, CPU Usage, CPU Frequency, RAM Usage, Any Diagnostic, Schedule Time, Code Spans, Event Counts, Assets Loading, Asset Memory, Input Latency, Gamepads, Static Text, Version, Render Backend, Manual Value, All-Time Records, Last Alert Spike, Channel (from other threads), Any Resource Field (via Reflection),
+//!        Wall Clock, Running Time, Time Since Mark, Lap Times, Intervals, Fixed Time Step, Fixed Overstep, Fixed Steps per Frame,
+//!        Cursor Position, Window Resolution, Window Scale Factor, Refresh Rate, Window Mode, Present Mode, Window Count,
+//!        Audio Latency (`audio` feature), Active Animations (`animation` feature)
 //!    - Implement your own custom entries to display anything you like!
 //!      - (see [`custom_minimal`](https://github.com/IyesGames/iyes_perf_ui/blob/v0.2.3/examples/custom_minimal.rs) and [`custom`](https://github.com/IyesGames/iyes_perf_ui/blob/v0.2.3/examples/custom.rs) examples)
 //!  - Customizable appearance/styling (see [`settings`](https://github.com/IyesGames/iyes_perf_ui/blob/v0.2.3/examples/settings.rs), [`fps_minimalist`](https://github.com/IyesGames/iyes_perf_ui/blob/v0.2.3/examples/fps_minimalist.rs) examples)
@@ -37,6 +38,13 @@
 //! ));
 //! ```
 //!
+//! The same can also be written as `commands.spawn_perf_ui(PerfUiRoot::default(), perf_ui_entries![...])`,
+//! using [`PerfUiCommandsExt`] and [`perf_ui_entries!`].
+//!
+//! If you only want the Perf UI in debug builds, add the plugin using
+//! `.add_plugins(PerfUiPlugin::debug_only())`. In release builds, it
+//! will not add anything to your app.
+//!
 //! If you want to customize the appearance, set the various fields in each of the
 //! structs, instead of using `default()`.
 //!
@@ -55,20 +63,56 @@ use bevy::prelude::*;
 pub mod prelude {
     pub use crate::{
         PerfUiPlugin,
+        PerfUiWithHistoryPlugin,
+        PerfUiDebugOnlyPlugin,
+        PerfUiEnabled,
         PerfUiAppExt,
+        PerfUiCommandsExt,
+        perf_ui_entries,
+    };
+    pub use crate::metrics::{
+        PerfUiMetricsPlugin,
+        PerfUiMetrics,
+        PerfUiDisplayCache,
+        PerfUiSnapshot,
     };
+    pub use crate::ui::copy::PerfUiCopyEvent;
     pub use crate::ui::root::{
         PerfUiRoot,
         PerfUiPosition,
+        PerfUiPage,
+    };
+    pub use crate::utils::{ColorGradient, MetricGoal, PerfUiFormatter, Smoothing};
+    pub use crate::format::{Notation, Precision};
+    pub use crate::entry::{PerfUiEntryRegistry, PerfUiThrottle};
+    pub use crate::alert::{
+        PerfUiThreshold,
+        PerfUiThresholdEvent,
+    };
+    pub use crate::conditions::{
+        perf_ui_visible,
+        perf_ui_value_breaches,
+        perf_ui_value_above,
+        perf_ui_value_below,
     };
-    pub use crate::utils::ColorGradient;
     #[cfg(feature = "entries")]
     pub use crate::entries::prelude::*;
     #[cfg(feature = "widgets")]
     pub use crate::widgets::prelude::*;
+    #[cfg(feature = "serde")]
+    pub use crate::layout::{
+        PerfUiLayout,
+        PerfUiEntryConfig,
+        save_perf_ui_layout,
+        load_perf_ui_layout,
+    };
 }
 
+pub mod alert;
+pub mod conditions;
 pub mod entry;
+pub mod format;
+pub mod metrics;
 pub mod ui;
 pub mod utils;
 
@@ -76,6 +120,8 @@ pub mod utils;
 pub mod entries;
 #[cfg(feature = "widgets")]
 pub mod widgets;
+#[cfg(feature = "serde")]
+pub mod layout;
 
 /// The Bevy Plugin
 #[derive(Default)]
@@ -83,16 +129,80 @@ pub struct PerfUiPlugin;
 
 impl Plugin for PerfUiPlugin {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::metrics::PerfUiMetricsPlugin>() {
+            app.add_plugins(crate::metrics::PerfUiMetricsPlugin);
+        }
         app.add_systems(Update, (
             crate::ui::root::setup_perf_ui
                 .run_if(crate::ui::root::rc_setup_perf_ui)
                 .in_set(PerfUiSet::Setup),
             crate::ui::sort_perf_ui_widgets
                 .run_if(crate::ui::rc_sort_perf_ui_widgets)
+                .run_if(rc_perf_ui_enabled)
                 .after(PerfUiSet::Setup),
         )
             .run_if(crate::ui::rc_any_visible)
         );
+        app.add_systems(Update,
+            crate::ui::root::apply_perf_ui_enabled
+                .run_if(resource_exists_and_changed::<PerfUiEnabled>)
+                .after(PerfUiSet::Setup)
+        );
+
+        app.add_systems(Update,
+            crate::ui::root::auto_hide_perf_ui_fullscreen
+                .run_if(crate::ui::root::rc_any_auto_hide_fullscreen)
+                .run_if(rc_perf_ui_enabled)
+                .after(PerfUiSet::Setup)
+        );
+
+        app.add_systems(Update,
+            crate::ui::collapse::setup_perf_ui_page_indicator
+                .run_if(crate::ui::root::rc_setup_perf_ui)
+                .after(crate::ui::root::setup_perf_ui)
+                .in_set(PerfUiSet::Setup)
+        );
+        app.add_systems(Update,
+            crate::ui::collapse::cycle_perf_ui_pages
+                .run_if(crate::ui::collapse::rc_any_perf_ui_pages)
+                .run_if(rc_perf_ui_enabled)
+                .before(crate::ui::collapse::apply_perf_ui_collapsed)
+        );
+        app.add_systems(Update,
+            crate::ui::collapse::apply_perf_ui_collapsed
+                .run_if(crate::ui::collapse::rc_apply_perf_ui_collapsed)
+                .after(PerfUiSet::Setup)
+                .after(crate::ui::sort_perf_ui_widgets)
+                .before(PerfUiSet::Update)
+        );
+
+        app.init_resource::<crate::ui::fade::PerfUiLastInput>();
+        app.add_systems(Update, (
+            crate::ui::fade::track_perf_ui_last_input,
+            crate::ui::fade::fade_perf_ui_idle,
+        )
+            .chain()
+            .in_set(PerfUiSet::Display)
+            .run_if(crate::ui::fade::rc_any_idle_fade)
+            .run_if(rc_perf_ui_enabled)
+        );
+
+        app.add_event::<crate::ui::copy::PerfUiCopyEvent>();
+        app.add_systems(Update, (
+            crate::ui::copy::perf_ui_click_to_copy,
+            crate::ui::copy::perf_ui_copy_flash,
+        )
+            .chain()
+            .in_set(PerfUiSet::Display)
+            .run_if(crate::ui::copy::rc_any_click_to_copy)
+            .run_if(rc_perf_ui_enabled)
+        );
+        #[cfg(feature = "clipboard")]
+        app.add_systems(Update,
+            crate::ui::copy::perf_ui_copy_to_clipboard
+                .after(crate::ui::copy::perf_ui_click_to_copy)
+                .in_set(PerfUiSet::Display)
+        );
 
         #[cfg(feature = "entries")]
         app.add_plugins(entries::predefined_entries_plugin);
@@ -101,6 +211,87 @@ impl Plugin for PerfUiPlugin {
     }
 }
 
+impl PerfUiPlugin {
+    /// Get a plugin that only adds the Perf UI in debug builds.
+    ///
+    /// In release builds (without `debug_assertions`), it does nothing.
+    /// All the Perf UI types remain available, so your code that spawns
+    /// Perf UI entities will still compile. They will just be inert
+    /// components that are never displayed or updated.
+    pub fn debug_only() -> PerfUiDebugOnlyPlugin {
+        PerfUiDebugOnlyPlugin
+    }
+
+    /// Get a plugin that adds the Perf UI, and also sets how many
+    /// measurements Bevy keeps for the FPS and frame time diagnostics
+    /// (Bevy's default is 120).
+    ///
+    /// A longer history makes the "worst" entries (like `PerfUiEntryFPSWorst`)
+    /// look further back, and makes the average (which is displayed until
+    /// the smoothed value is available) slower to respond. It does not change
+    /// how much the `smoothed` values are smoothed (that is up to Bevy's
+    /// exponential smoothing), nor entries with their own `smoothing`.
+    ///
+    /// You still need to add Bevy's `FrameTimeDiagnosticsPlugin`. If you
+    /// have already set a custom history length for these diagnostics
+    /// yourself, it is kept.
+    pub fn with_history_length(history_length: usize) -> PerfUiWithHistoryPlugin {
+        PerfUiWithHistoryPlugin {
+            history_length,
+        }
+    }
+}
+
+/// Plugin that adds [`PerfUiPlugin`] and sets the history length of the
+/// frame time diagnostics.
+///
+/// Created using [`PerfUiPlugin::with_history_length`].
+pub struct PerfUiWithHistoryPlugin {
+    /// How many measurements to keep.
+    pub history_length: usize,
+}
+
+impl Plugin for PerfUiWithHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<PerfUiPlugin>() {
+            app.add_plugins(PerfUiPlugin);
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        use bevy::diagnostic::{Diagnostic, DiagnosticsStore, FrameTimeDiagnosticsPlugin, DEFAULT_MAX_HISTORY_LENGTH};
+        // by now, the diagnostics plugin has registered them,
+        // regardless of the order the plugins were added in
+        let Some(mut store) = app.world_mut().get_resource_mut::<DiagnosticsStore>() else {
+            return;
+        };
+        for path in [FrameTimeDiagnosticsPlugin::FPS, FrameTimeDiagnosticsPlugin::FRAME_TIME] {
+            let Some(diagnostic) = store.get_mut(&path) else {
+                continue;
+            };
+            // keep the user's own setting
+            if diagnostic.get_max_history_length() != DEFAULT_MAX_HISTORY_LENGTH {
+                continue;
+            }
+            let old = std::mem::replace(diagnostic, Diagnostic::new(path));
+            *diagnostic = old.with_max_history_length(self.history_length);
+        }
+    }
+}
+
+/// Plugin that adds [`PerfUiPlugin`] only in debug builds.
+///
+/// Created using [`PerfUiPlugin::debug_only`].
+#[derive(Default)]
+pub struct PerfUiDebugOnlyPlugin;
+
+impl Plugin for PerfUiDebugOnlyPlugin {
+    fn build(&self, _app: &mut App) {
+        #[cfg(debug_assertions)]
+        _app.add_plugins(PerfUiPlugin);
+    }
+}
+
 /// Extension trait for adding new types of Perf UI Entries.
 pub trait PerfUiAppExt {
     /// Add support for a custom Perf UI Widget type (component).
@@ -122,9 +313,49 @@ pub trait PerfUiAppExt {
     /// also calling `add_perf_ui_widget` to add support for displaying
     /// your entry using different UI widgets.
     fn add_perf_ui_simple_entry<T: crate::entry::PerfUiEntry>(&mut self) -> &mut Self {
+        self.add_perf_ui_entry_metrics::<T>();
         self.add_perf_ui_widget::<T, T>();
         self
     }
+
+    /// Add support for collecting the values of a Perf UI Entry type (component)
+    /// into [`PerfUiMetrics`](crate::metrics::PerfUiMetrics).
+    ///
+    /// This does not add any UI. It is done automatically by `add_perf_ui_simple_entry`,
+    /// so you only need to call this if you want to collect metrics without the UI.
+    ///
+    /// Calling this more than once for the same type has no effect.
+    fn add_perf_ui_entry_metrics<T: crate::entry::PerfUiEntry>(&mut self) -> &mut Self;
+
+    /// Add the default label and constructor of a Perf UI Entry type
+    /// to the [`PerfUiEntryRegistry`](crate::entry::PerfUiEntryRegistry).
+    ///
+    /// Entry types are added to the registry automatically when they are
+    /// registered (using `add_perf_ui_simple_entry` or `add_perf_ui_entry_metrics`),
+    /// but without this, tooling cannot know their label or create them.
+    fn add_perf_ui_entry_info<T: crate::entry::PerfUiEntry + Default>(&mut self) -> &mut Self;
+
+    /// Add an alert: run a system when the value of an entry crosses a threshold.
+    ///
+    /// `label` is the label of the entry to watch (as returned by
+    /// `PerfUiEntry::label`). The system is run once whenever the
+    /// value crosses into the threshold (edge-triggered), and is given the
+    /// current value as input (`In<f64>`). A `PerfUiThresholdEvent`
+    /// is also sent.
+    ///
+    /// Only entries that provide `PerfUiEntry::value_as_f64` can trigger alerts.
+    ///
+    /// ```rust
+    /// app.add_perf_ui_alert("FPS", PerfUiThreshold::Below(30.0), |In(fps): In<f64>| {
+    ///     warn!("Low FPS: {}", fps);
+    /// });
+    /// ```
+    fn add_perf_ui_alert<M>(
+        &mut self,
+        label: impl Into<String>,
+        threshold: crate::alert::PerfUiThreshold,
+        system: impl IntoSystem<In<f64>, (), M> + 'static,
+    ) -> &mut Self;
 }
 
 impl PerfUiAppExt for App {
@@ -145,9 +376,58 @@ impl PerfUiAppExt for App {
         ));
         self
     }
+
+    fn add_perf_ui_entry_metrics<T: crate::entry::PerfUiEntry>(&mut self) -> &mut Self {
+        let is_new = self.world_mut()
+            .get_resource_or_init::<crate::metrics::PerfUiMetrics>()
+            .registered
+            .insert(std::any::TypeId::of::<T>());
+        if !is_new {
+            return self;
+        }
+        self.init_resource::<crate::metrics::PerfUiDisplayCache>();
+        self.world_mut()
+            .get_resource_or_init::<crate::entry::PerfUiEntryRegistry>()
+            .register::<T>();
+        // fails if `T` was already spawned; `collect_perf_ui_metrics` inserts it then
+        let _ = self.try_register_required_components::<T, crate::metrics::PerfUiEntryOutput<T>>();
+        self.add_systems(Update,
+            crate::metrics::collect_perf_ui_metrics::<T>
+                .run_if(any_with_component::<T>)
+                .before(crate::ui::widget::update_perf_ui_widget::<T, T>)
+                .in_set(PerfUiSet::Update),
+        );
+        self
+    }
+
+    fn add_perf_ui_entry_info<T: crate::entry::PerfUiEntry + Default>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<crate::entry::PerfUiEntryRegistry>()
+            .register_default::<T>();
+        self
+    }
+
+    fn add_perf_ui_alert<M>(
+        &mut self,
+        label: impl Into<String>,
+        threshold: crate::alert::PerfUiThreshold,
+        system: impl IntoSystem<In<f64>, (), M> + 'static,
+    ) -> &mut Self {
+        let id = self.world_mut().register_system(system);
+        self.world_mut()
+            .get_resource_or_init::<crate::alert::PerfUiAlerts>()
+            .add(label, threshold, id);
+        self
+    }
 }
 
 /// System Set to allow you to order things relative to our systems.
+///
+/// All of them run in the `Update` schedule, in this order: `Setup`, `Update`, `Display`.
+/// For example, run the systems that produce your own metrics (such as
+/// for `PerfUiEntryManual`) `.before(PerfUiSet::Update)`, so that their
+/// values are displayed in the same frame. Run anything that needs the
+/// final state of the Perf UI `.after(PerfUiSet::Display)`.
 #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PerfUiSet {
     /// Systems that manage (spawn) the Perf UI entity hierarchy.
@@ -156,4 +436,81 @@ pub enum PerfUiSet {
     ///
     /// If you care about a specific entry only, refer to the `update_perf_ui_entry::<T>` system instead.
     Update,
+    /// Systems that apply visual effects to the whole Perf UI (such as fading when idle),
+    /// and handle interaction with it (such as click-to-copy), after the values are updated.
+    Display,
+}
+
+/// Extension trait for spawning Perf UIs.
+pub trait PerfUiCommandsExt {
+    /// Spawn a Perf UI with the given root settings and entries.
+    ///
+    /// All the entries are inserted on the same entity as the [`PerfUiRoot`](crate::ui::root::PerfUiRoot).
+    /// Any bundle works, but to list many entries of different types,
+    /// the [`perf_ui_entries!`] macro is convenient:
+    ///
+    /// ```rust
+    /// commands.spawn_perf_ui(
+    ///     PerfUiRoot::default(),
+    ///     perf_ui_entries![
+    ///         PerfUiEntryFPS::default(),
+    ///         PerfUiEntryClock::default(),
+    ///     ],
+    /// );
+    /// ```
+    fn spawn_perf_ui(&mut self, root: crate::ui::root::PerfUiRoot, entries: impl Bundle) -> EntityCommands<'_>;
+}
+
+impl PerfUiCommandsExt for Commands<'_, '_> {
+    fn spawn_perf_ui(&mut self, root: crate::ui::root::PerfUiRoot, entries: impl Bundle) -> EntityCommands<'_> {
+        self.spawn((root, entries))
+    }
+}
+
+/// Combine any number of Perf UI entries (of different types) into one bundle.
+///
+/// Unlike a plain tuple, this is not limited in how many entries it can hold.
+///
+/// ```rust
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     perf_ui_entries![
+///         PerfUiEntryFPS::default(),
+///         PerfUiEntryFrameTime::default(),
+///         PerfUiEntryClock::default(),
+///     ],
+/// ));
+/// ```
+#[macro_export]
+macro_rules! perf_ui_entries {
+    () => {
+        ()
+    };
+    ($entry:expr $(, $rest:expr)* $(,)?) => {
+        ($entry, $crate::perf_ui_entries!($($rest),*))
+    };
+}
+
+/// Resource to globally enable or disable the Perf UI.
+///
+/// This is a master switch that affects all Perf UIs at once. When set to
+/// `PerfUiEnabled(false)`, all Perf UIs are hidden and none of our systems
+/// will run, so they have no performance cost. Setting it back to `true`
+/// restores everything as it was.
+///
+/// Unlike changing the `Visibility` of the Perf UI root entity, this also
+/// stops collecting [`PerfUiMetrics`](crate::metrics::PerfUiMetrics).
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerfUiEnabled(pub bool);
+
+impl Default for PerfUiEnabled {
+    fn default() -> Self {
+        PerfUiEnabled(true)
+    }
+}
+
+pub(crate) fn rc_perf_ui_enabled(
+    enabled: Option<Res<PerfUiEnabled>>,
+) -> bool {
+    enabled.map(|e| e.0).unwrap_or(true)
 }
@@ -0,0 +1,371 @@
+//! Collecting the values of Perf UI entries, independently of the UI.
+//!
+//! [`PerfUiMetricsPlugin`] maintains the [`PerfUiMetrics`] resource, which
+//! contains the latest numeric value of every entry that is currently spawned,
+//! keyed by the entry's label. This does not require any UI to be displayed,
+//! which makes it useful for automated performance tests:
+//!
+//! ```rust
+//! App::new()
+//!     .add_plugins(MinimalPlugins)
+//!     .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin)
+//!     .add_plugins(PerfUiMetricsPlugin)
+//!     .add_systems(Startup, |mut commands: Commands| {
+//!         commands.spawn(PerfUiEntryFPS::default());
+//!     })
+//!     .add_systems(Last, |metrics: Res<PerfUiMetrics>| {
+//!         if let Some(fps) = metrics.get("FPS") {
+//!             assert!(fps > 30.0);
+//!         }
+//!     });
+//! ```
+//!
+//! The text that would be displayed for each entry is also available,
+//! in the [`PerfUiDisplayCache`] resource.
+//!
+//! [`PerfUiPlugin`](crate::PerfUiPlugin) adds this plugin automatically.
+
+use bevy::prelude::*;
+use bevy::ecs::system::StaticSystemParam;
+use bevy::utils::{Duration, HashMap, HashSet};
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+use crate::entry::PerfUiEntry;
+use crate::ui::root::PerfUiRoot;
+use crate::{PerfUiEnabled, PerfUiSet};
+
+/// Plugin to collect the values of Perf UI entries, without any UI.
+///
+/// See the [module-level docs](self) for more info.
+#[derive(Default)]
+pub struct PerfUiMetricsPlugin;
+
+impl Plugin for PerfUiMetricsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PerfUiMetrics>();
+        app.init_resource::<PerfUiDisplayCache>();
+        app.init_resource::<PerfUiEnabled>();
+        // our system sets are shared by the metrics and the UI,
+        // so this also disables the UI
+        app.configure_sets(Update, (
+            PerfUiSet::Setup,
+            PerfUiSet::Update,
+            PerfUiSet::Display,
+        ).chain().run_if(crate::rc_perf_ui_enabled));
+        app.add_systems(Update,
+            finish_perf_ui_metrics
+                .run_if(crate::rc_perf_ui_enabled)
+                .after(PerfUiSet::Update)
+        );
+
+        app.add_event::<crate::alert::PerfUiThresholdEvent>();
+        app.init_resource::<crate::alert::PerfUiAlerts>();
+        app.add_systems(Update,
+            crate::alert::check_perf_ui_alerts
+                .run_if(crate::alert::rc_any_perf_ui_alerts)
+                .run_if(crate::rc_perf_ui_enabled)
+                .after(finish_perf_ui_metrics)
+        );
+
+        #[cfg(feature = "entries")]
+        app.add_plugins(crate::entries::predefined_entries_metrics_plugin);
+    }
+}
+
+/// Resource containing the latest numeric values of all Perf UI entries.
+///
+/// Values are keyed by the entry's label, and only available for entries
+/// that provide [`PerfUiEntry::value_as_f64`]. Updated every frame, after
+/// `PerfUiSet::Update`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiMetrics {
+    values: HashMap<String, f64>,
+    next: HashMap<String, f64>,
+    pub(crate) registered: HashSet<TypeId>,
+}
+
+impl PerfUiMetrics {
+    /// Get the latest value of the entry with the given label.
+    pub fn get(&self, label: &str) -> Option<f64> {
+        self.values.get(label).copied()
+    }
+
+    /// Iterate over the labels and latest values of all entries.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Capture the latest values of all entries, to compare them later.
+    pub fn snapshot(&self) -> PerfUiSnapshot {
+        PerfUiSnapshot {
+            values: self.values.clone(),
+        }
+    }
+}
+
+/// The values of all Perf UI entries at some point in time.
+///
+/// Made using [`PerfUiMetrics::snapshot`]. Useful for A/B comparisons:
+/// take a snapshot, change something, take another snapshot, and
+/// [`diff`](Self::diff) them.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerfUiSnapshot {
+    values: HashMap<String, f64>,
+}
+
+impl PerfUiSnapshot {
+    /// Get the value of the entry with the given label.
+    pub fn get(&self, label: &str) -> Option<f64> {
+        self.values.get(label).copied()
+    }
+
+    /// Iterate over the labels and values of all entries.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Compare with a later snapshot.
+    ///
+    /// Returns `(label, before, after, delta)` for every entry in either
+    /// snapshot, sorted by label. The delta is `after - before`. If an
+    /// entry is only in one of the snapshots, its value in the other
+    /// one (and the delta) is NaN.
+    pub fn diff(&self, other: &Self) -> Vec<(String, f64, f64, f64)> {
+        let mut labels: Vec<&String> = self.values.keys()
+            .chain(other.values.keys().filter(|k| !self.values.contains_key(*k)))
+            .collect();
+        labels.sort();
+        labels.into_iter()
+            .map(|label| {
+                let before = self.values.get(label).copied().unwrap_or(f64::NAN);
+                let after = other.values.get(label).copied().unwrap_or(f64::NAN);
+                (label.clone(), before, after, after - before)
+            })
+            .collect()
+    }
+}
+
+/// Resource containing the latest formatted values of all Perf UI entries.
+///
+/// This is the text that would be displayed in the Perf UI, keyed by
+/// the entry's label. Useful for printing values in a debug console.
+/// Entries whose value is currently unavailable are not included.
+/// Updated every frame, after `PerfUiSet::Update`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PerfUiDisplayCache {
+    values: HashMap<String, String>,
+    next: HashMap<String, String>,
+}
+
+impl PerfUiDisplayCache {
+    /// Get the latest formatted value of the entry with the given label.
+    pub fn get(&self, label: &str) -> Option<&str> {
+        self.values.get(label).map(|s| s.as_str())
+    }
+
+    /// Iterate over the labels and latest formatted values of all entries.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// The latest evaluation of the Perf UI entry of type `E` on the same entity.
+///
+/// Produced by [`collect_perf_ui_metrics`], so that each entry is only
+/// evaluated once per frame. The simple widget displays this, rather than
+/// calling `update_value` again.
+#[doc(hidden)]
+#[derive(Component)]
+pub struct PerfUiEntryOutput<E: PerfUiEntry> {
+    /// The formatted value, if available.
+    pub(crate) parts: Option<Vec<String>>,
+    pub(crate) highlight: bool,
+    /// The color based on the value (or the entry's fixed color).
+    pub(crate) color: Option<Color>,
+    /// When `update_value` last returned a value, as per `Time<Real>::elapsed()`.
+    pub(crate) updated_at: Option<Duration>,
+    _pd: PhantomData<E>,
+}
+
+impl<E: PerfUiEntry> Default for PerfUiEntryOutput<E> {
+    fn default() -> Self {
+        PerfUiEntryOutput {
+            parts: None,
+            highlight: false,
+            color: None,
+            updated_at: None,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<E: PerfUiEntry> PerfUiEntryOutput<E> {
+    fn update(
+        &mut self,
+        entry: &E,
+        root: Option<&PerfUiRoot>,
+        value: Option<&E::Value>,
+        now: Duration,
+    ) {
+        let Some(value) = value else {
+            // keep displaying the last value, so it can become stale
+            if entry.stale_after().is_none() {
+                self.parts = None;
+            }
+            return;
+        };
+        self.parts = Some(entry.format_value_parts(value));
+        self.highlight = entry.value_highlight(value);
+        self.color = entry.fixed_color().or_else(|| entry.value_color_with_palette(
+            value,
+            root.and_then(|root| root.color_good),
+            root.and_then(|root| root.color_bad),
+        ));
+        self.updated_at = Some(now);
+    }
+
+    /// Has the value not been updated for longer than the entry's `stale_after`?
+    pub(crate) fn is_stale(&self, entry: &E, now: Duration) -> bool {
+        match (entry.stale_after(), self.updated_at) {
+            (Some(after), Some(at)) => now.saturating_sub(at) > after,
+            _ => false,
+        }
+    }
+}
+
+/// System that collects the values of Perf UI entries of a given type
+///
+/// This is where entries are evaluated (`update_value` is called), once
+/// per frame. The UI displays the results.
+///
+/// Exposed as `pub` so you can refer to it for ordering.
+pub fn collect_perf_ui_metrics<E: PerfUiEntry>(
+    time: Res<Time<Real>>,
+    mut metrics: ResMut<PerfUiMetrics>,
+    mut display: ResMut<PerfUiDisplayCache>,
+    mut q_entry: Query<(Entity, &E, Option<&PerfUiRoot>, Option<&mut PerfUiEntryOutput<E>>)>,
+    entry_param: StaticSystemParam<E::SystemParam>,
+    mut commands: Commands,
+) {
+    let mut entry_param = entry_param.into_inner();
+    let metrics = &mut *metrics;
+    let display = &mut *display;
+    for (e_entry, entry, root, output) in &mut q_entry {
+        if !entry.enabled() {
+            continue;
+        }
+        if let Some(throttle) = entry.throttle() {
+            if !throttle.is_due(time.elapsed(), time.delta()) {
+                // keep the previous values
+                let label = entry.label();
+                if let Some(value) = metrics.values.get(label) {
+                    metrics.next.insert(label.to_owned(), *value);
+                }
+                if let Some(s) = display.values.get(label) {
+                    display.next.insert(label.to_owned(), s.clone());
+                }
+                continue;
+            }
+        }
+        let value = entry.update_value(&mut entry_param);
+        if let Some(mut output) = output {
+            output.update(entry, root, value.as_ref(), time.elapsed());
+        } else {
+            // the entry was spawned before its type was registered
+            let mut output = PerfUiEntryOutput::<E>::default();
+            output.update(entry, root, value.as_ref(), time.elapsed());
+            commands.entity(e_entry).insert(output);
+        }
+        let Some(value) = value else {
+            continue;
+        };
+        if let Some(value) = entry.value_as_f64(&value) {
+            metrics.next.insert(entry.label().to_owned(), value);
+        }
+        display.next.insert(entry.label().to_owned(), entry.format_value(&value));
+    }
+}
+
+pub(crate) fn finish_perf_ui_metrics(
+    mut metrics: ResMut<PerfUiMetrics>,
+    mut display: ResMut<PerfUiDisplayCache>,
+) {
+    let metrics = &mut *metrics;
+    std::mem::swap(&mut metrics.values, &mut metrics.next);
+    metrics.next.clear();
+    let display = &mut *display;
+    std::mem::swap(&mut display.values, &mut display.next);
+    display.next.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use bevy::prelude::*;
+    use bevy::ecs::system::SystemParam;
+
+    use crate::prelude::*;
+    use crate::entry::PerfUiEntry;
+    use crate::ui::widget::PerfUiWidgetMarker;
+
+    /// Counts how many times it has been evaluated.
+    #[derive(Component, Default)]
+    #[require(PerfUiRoot)]
+    struct CountingEntry {
+        calls: AtomicU32,
+    }
+
+    impl PerfUiEntry for CountingEntry {
+        type Value = u32;
+        type SystemParam = ();
+
+        fn label(&self) -> &str {
+            "Calls"
+        }
+        fn sort_key(&self) -> i32 {
+            0
+        }
+        fn update_value(
+            &self,
+            _: &mut <Self::SystemParam as SystemParam>::Item<'_, '_>,
+        ) -> Option<Self::Value> {
+            Some(self.calls.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+    }
+
+    #[test]
+    fn entries_are_evaluated_once_per_frame() {
+        let mut app = crate::utils::test_app();
+        app.add_perf_ui_simple_entry::<CountingEntry>();
+        app.world_mut().spawn(CountingEntry::default());
+        for _ in 0..3 {
+            app.update();
+        }
+        let world = app.world_mut();
+        // both the UI and the metrics are there…
+        assert_eq!(world.query::<&PerfUiWidgetMarker<CountingEntry>>().iter(world).count(), 1);
+        assert_eq!(world.resource::<PerfUiDisplayCache>().get("Calls"), Some("3"));
+        // …but the entry was only evaluated once per frame
+        let entry = world.query::<&CountingEntry>().single(world);
+        assert_eq!(entry.calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[cfg(feature = "entries")]
+    #[test]
+    fn display_cache_after_one_update() {
+        // no UI needed
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, PerfUiMetricsPlugin));
+        let entry = PerfUiEntryManual::new("Score");
+        entry.set_value(42.0);
+        app.world_mut().spawn(entry);
+        app.update();
+        let display = app.world().resource::<PerfUiDisplayCache>();
+        assert_eq!(display.get("Score").map(str::trim), Some("42.00"));
+        assert_eq!(display.iter().count(), 1);
+        assert_eq!(app.world().resource::<PerfUiMetrics>().get("Score"), Some(42.0));
+    }
+}
@@ -3,12 +3,20 @@
 use bevy::prelude::*;
 use self::root::PerfUiRoot;
 
+pub mod copy;
 pub mod root;
 pub mod widget;
 
+pub(crate) mod collapse;
+pub(crate) mod fade;
+
 #[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct PerfUiSortKey(i32);
 
+/// The label of the entry displayed by a widget.
+#[derive(Component, Clone, PartialEq, Eq)]
+pub(crate) struct PerfUiWidgetLabel(String);
+
 pub(crate) fn rc_sort_perf_ui_widgets(
     q: Query<(), (With<PerfUiRoot>, Changed<Children>)>,
 ) -> bool {
@@ -16,11 +24,17 @@ pub(crate) fn rc_sort_perf_ui_widgets(
 }
 
 pub(crate) fn sort_perf_ui_widgets(
-    mut q_root: Query<&mut Children, (With<PerfUiRoot>, Changed<Children>)>,
+    mut q_root: Query<(&PerfUiRoot, &mut Children, Option<&mut Node>), Changed<Children>>,
     q_sortkey: Query<&PerfUiSortKey>,
 ) {
-    for mut children in &mut q_root {
+    for (root, mut children, node) in &mut q_root {
         children.sort_by_key(|e| q_sortkey.get(*e).map(|k| k.0).unwrap_or(0));
+        if let Some(mut node) = node {
+            let rows = root.grid_rows(children.len());
+            if node.grid_template_rows != rows {
+                node.grid_template_rows = rows;
+            }
+        }
     }
 }
 
@@ -0,0 +1,158 @@
+//! Collapsing the Perf UI down to a single summary entry, and paging
+//! through subsets of the entries.
+
+use bevy::prelude::*;
+
+use crate::ui::root::{PerfUiPage, PerfUiRoot};
+use crate::ui::widget::PerfUiWidgetFresh;
+use super::{PerfUiSortKey, PerfUiWidgetLabel};
+
+/// Marker for widgets hidden because their Perf UI is collapsed,
+/// or because they are not on the current page.
+///
+/// Such widgets are not updated, until they are displayed again.
+#[derive(Component)]
+pub(crate) struct PerfUiWidgetCollapsed;
+
+/// Marker for the text displaying the current page number.
+#[derive(Component)]
+pub(crate) struct PerfUiPageIndicator;
+
+pub(crate) fn rc_apply_perf_ui_collapsed(
+    q: Query<(), (With<PerfUiRoot>, Or<(Changed<PerfUiRoot>, Changed<Children>, Changed<PerfUiPage>)>)>,
+) -> bool {
+    !q.is_empty()
+}
+
+pub(crate) fn apply_perf_ui_collapsed(
+    mut commands: Commands,
+    q_root: Query<
+        (&PerfUiRoot, &Children, Option<&PerfUiPage>),
+        Or<(Changed<PerfUiRoot>, Changed<Children>, Changed<PerfUiPage>)>,
+    >,
+    mut q_widget: Query<
+        (Option<&PerfUiWidgetLabel>, &mut Node, Has<PerfUiWidgetCollapsed>),
+        (With<PerfUiSortKey>, Without<PerfUiPageIndicator>),
+    >,
+    mut q_indicator: Query<(&mut Text, &mut Node), With<PerfUiPageIndicator>>,
+) {
+    for (root, children, page) in &q_root {
+        let n_entries = children.iter()
+            .filter(|e| q_widget.contains(**e))
+            .count();
+        let n_pages = root.n_pages(n_entries);
+        let page = page.map(|p| p.0 % n_pages).unwrap_or(0);
+        let mut i_entry = 0;
+        for e_widget in children.iter() {
+            if let Ok((mut text, mut node)) = q_indicator.get_mut(*e_widget) {
+                let indicator = format!("{}/{}", page + 1, n_pages);
+                if text.0 != indicator {
+                    text.0 = indicator;
+                }
+                node.display = if root.collapsed {
+                    Display::None
+                } else {
+                    Display::DEFAULT
+                };
+                continue;
+            }
+            let Ok((label, mut node, was_collapsed)) = q_widget.get_mut(*e_widget) else {
+                continue;
+            };
+            let on_page = root.page_size
+                .is_none_or(|size| i_entry / size.max(1) == page);
+            i_entry += 1;
+            let is_summary = match (label, &root.collapsed_summary) {
+                (Some(label), Some(summary)) => label.0 == *summary,
+                _ => false,
+            };
+            let collapse = if root.collapsed {
+                !is_summary
+            } else {
+                !on_page
+            };
+            if collapse && !was_collapsed {
+                node.display = Display::None;
+                commands.entity(*e_widget).insert(PerfUiWidgetCollapsed);
+            } else if !collapse && was_collapsed {
+                node.display = Display::DEFAULT;
+                // update it immediately, instead of showing an outdated value
+                commands.entity(*e_widget)
+                    .remove::<PerfUiWidgetCollapsed>()
+                    .insert(PerfUiWidgetFresh);
+            }
+        }
+    }
+}
+
+pub(crate) fn setup_perf_ui_page_indicator(
+    mut commands: Commands,
+    q_root: Query<(Entity, &PerfUiRoot, Option<&Children>), Changed<PerfUiRoot>>,
+    q_indicator: Query<(), With<PerfUiPageIndicator>>,
+) {
+    for (e_root, root, children) in &q_root {
+        // respawn it, in case the style has changed
+        for e_child in children.into_iter().flatten() {
+            if q_indicator.contains(*e_child) {
+                commands.entity(e_root).remove_children(&[*e_child]);
+                commands.entity(*e_child).despawn_recursive();
+            }
+        }
+        if root.page_size.is_none() {
+            continue;
+        }
+        let e_indicator = commands.spawn((
+            PerfUiPageIndicator,
+            // always after all the entries
+            PerfUiSortKey(i32::MAX),
+            Text::default(),
+            TextFont {
+                font: root.font_label.clone(),
+                font_size: root.fontsize_label * root.ui_scale,
+                ..default()
+            },
+            TextColor(root.label_color),
+            Node {
+                align_self: AlignSelf::End,
+                margin: UiRect::all(root.px(root.inner_margin)),
+                padding: UiRect::all(root.px(4.0)),
+                ..default()
+            },
+        )).id();
+        commands.entity(e_root).add_child(e_indicator);
+    }
+}
+
+pub(crate) fn rc_any_perf_ui_pages(
+    q_root: Query<&PerfUiRoot>,
+) -> bool {
+    q_root.iter().any(|root| root.page_size.is_some() && root.page_key.is_some())
+}
+
+pub(crate) fn cycle_perf_ui_pages(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut q_root: Query<(Entity, &PerfUiRoot, Option<&Children>, Option<&mut PerfUiPage>)>,
+    q_widget: Query<(), (With<PerfUiSortKey>, Without<PerfUiPageIndicator>)>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    for (e_root, root, children, page) in &mut q_root {
+        let (Some(_), Some(key)) = (root.page_size, root.page_key) else {
+            continue;
+        };
+        if !keys.just_pressed(key) {
+            continue;
+        }
+        let n_entries = children.into_iter().flatten()
+            .filter(|e| q_widget.contains(**e))
+            .count();
+        let n_pages = root.n_pages(n_entries);
+        if let Some(mut page) = page {
+            page.0 = (page.0 % n_pages + 1) % n_pages;
+        } else {
+            commands.entity(e_root).insert(PerfUiPage(1 % n_pages));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Clicking on a value to copy it.
+//!
+//! If [`PerfUiRoot::click_to_copy`] is enabled, clicking on the value of a
+//! simple entry sends a [`PerfUiCopyEvent`] with the displayed text, and
+//! briefly flashes the value to confirm the click.
+//!
+//! With the `clipboard` cargo feature, the text (like "FPS: 60") is also put
+//! into the system clipboard (using the `arboard` crate). If no clipboard is
+//! available, it is silently ignored.
+//!
+//! Without the feature, putting the text into the clipboard is up to you.
+//! For example, using `arboard` yourself:
+//!
+//! ```rust,ignore
+//! use bevy::prelude::*;
+//! use iyes_perf_ui::prelude::*;
+//!
+//! fn copy_to_clipboard(mut evr_copy: EventReader<PerfUiCopyEvent>) {
+//!     for ev in evr_copy.read() {
+//!         if let Ok(mut clipboard) = arboard::Clipboard::new() {
+//!             // if no clipboard is available, just ignore it
+//!             let _ = clipboard.set_text(format!("{}: {}", ev.label, ev.text));
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::utils::Duration;
+
+use crate::metrics::PerfUiDisplayCache;
+use crate::ui::root::PerfUiRoot;
+
+/// How long the value flashes for, after it was clicked.
+const FLASH_DURATION: Duration = Duration::from_millis(300);
+
+/// The color of the flash, at its brightest.
+const FLASH_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.25);
+
+/// Event sent when the user clicks on a value, to copy it.
+#[derive(Event, Debug, Clone)]
+pub struct PerfUiCopyEvent {
+    /// The label of the entry that was clicked.
+    pub label: String,
+    /// The text of the value, as currently displayed.
+    pub text: String,
+}
+
+/// Component on the value node of entries that can be clicked to copy.
+#[derive(Component)]
+pub(crate) struct PerfUiCopyTarget {
+    pub(crate) label: String,
+    /// When the value was last clicked, as per `Time<Real>::elapsed()`.
+    flash_start: Option<Duration>,
+}
+
+impl PerfUiCopyTarget {
+    pub(crate) fn new(label: &str) -> Self {
+        PerfUiCopyTarget {
+            label: label.to_owned(),
+            flash_start: None,
+        }
+    }
+}
+
+pub(crate) fn rc_any_click_to_copy(
+    q_root: Query<&PerfUiRoot>,
+) -> bool {
+    q_root.iter().any(|root| root.click_to_copy)
+}
+
+pub(crate) fn perf_ui_click_to_copy(
+    time: Res<Time<Real>>,
+    display: Option<Res<PerfUiDisplayCache>>,
+    mut evw_copy: EventWriter<PerfUiCopyEvent>,
+    mut q_target: Query<(&Interaction, &mut PerfUiCopyTarget), Changed<Interaction>>,
+) {
+    for (interaction, mut target) in &mut q_target {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // entries whose value is unavailable are not in the cache
+        let Some(text) = display.as_ref().and_then(|d| d.get(&target.label)) else {
+            continue;
+        };
+        evw_copy.send(PerfUiCopyEvent {
+            label: target.label.clone(),
+            text: text.trim().to_owned(),
+        });
+        target.flash_start = Some(time.elapsed());
+    }
+}
+
+pub(crate) fn perf_ui_copy_flash(
+    time: Res<Time<Real>>,
+    mut q_target: Query<(&mut PerfUiCopyTarget, &mut BackgroundColor)>,
+) {
+    for (mut target, mut background) in &mut q_target {
+        let Some(start) = target.flash_start else {
+            continue;
+        };
+        let t = time.elapsed().saturating_sub(start).as_secs_f32()
+            / FLASH_DURATION.as_secs_f32();
+        if t >= 1.0 {
+            target.flash_start = None;
+            background.0 = Color::NONE;
+        } else {
+            background.0 = FLASH_COLOR.with_alpha(FLASH_COLOR.alpha() * (1.0 - t));
+        }
+    }
+}
+
+/// Put the text of every [`PerfUiCopyEvent`] into the system clipboard.
+#[cfg(feature = "clipboard")]
+pub(crate) fn perf_ui_copy_to_clipboard(
+    mut evr_copy: EventReader<PerfUiCopyEvent>,
+) {
+    for ev in evr_copy.read() {
+        // if no clipboard is available, just ignore it
+        let _ = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(format!("{}: {}", ev.label, ev.text)));
+    }
+}
@@ -0,0 +1,130 @@
+//! Fading out the Perf UI when the user is idle.
+
+use bevy::prelude::*;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButtonInput, MouseWheel};
+use bevy::input::touch::TouchInput;
+use bevy::utils::Duration;
+
+use crate::ui::root::PerfUiRoot;
+
+/// How long it takes to fade out, once the idle delay has passed.
+const FADE_OUT_DURATION: Duration = Duration::from_millis(500);
+
+/// Resource to keep track of when the user last provided any input.
+///
+/// Represented as a duration since startup, as per `Time<Real>::elapsed()`.
+#[derive(Resource, Default)]
+pub(crate) struct PerfUiLastInput(Duration);
+
+/// Remembers the colors of a UI node before fading was applied.
+///
+/// Each pair is `(base, faded)`. If the node's current color no longer matches
+/// `faded`, something else (like a widget update) has changed it, and the
+/// current color becomes the new base.
+#[derive(Component, Default)]
+pub(crate) struct PerfUiFadeTracker {
+    text: Option<(Color, Color)>,
+    background: Option<(Color, Color)>,
+}
+
+pub(crate) fn rc_any_idle_fade(
+    q_root: Query<&PerfUiRoot>,
+) -> bool {
+    q_root.iter().any(|root| root.idle_fade.is_some())
+}
+
+pub(crate) fn track_perf_ui_last_input(
+    time: Res<Time<Real>>,
+    mut last_input: ResMut<PerfUiLastInput>,
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut evr_mouse: EventReader<MouseButtonInput>,
+    mut evr_wheel: EventReader<MouseWheel>,
+    mut evr_cursor: EventReader<CursorMoved>,
+    mut evr_touch: EventReader<TouchInput>,
+) {
+    let n_input = evr_kbd.read().count()
+        + evr_mouse.read().count()
+        + evr_wheel.read().count()
+        + evr_cursor.read().count()
+        + evr_touch.read().count();
+    if n_input > 0 {
+        last_input.0 = time.elapsed();
+    }
+}
+
+fn fade_color(base: Color, factor: f32) -> Color {
+    base.with_alpha(base.alpha() * factor)
+}
+
+pub(crate) fn fade_perf_ui_idle(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    last_input: Res<PerfUiLastInput>,
+    mut q_root: Query<(Entity, &PerfUiRoot, &mut BackgroundColor, Option<&mut BorderColor>)>,
+    q_children: Query<&Children>,
+    mut q_node: Query<(
+        Option<&mut TextColor>,
+        Option<&mut BackgroundColor>,
+        Option<&mut PerfUiFadeTracker>,
+    ), Without<PerfUiRoot>>,
+) {
+    let idle = time.elapsed().saturating_sub(last_input.0);
+    for (e_root, root, mut background, border_color) in &mut q_root {
+        let Some((delay, faded_alpha)) = root.idle_fade else {
+            continue;
+        };
+        let t = if idle > delay {
+            ((idle - delay).as_secs_f32() / FADE_OUT_DURATION.as_secs_f32()).min(1.0)
+        } else {
+            0.0
+        };
+        let factor = 1.0 + (faded_alpha - 1.0) * t;
+
+        let new_background = fade_color(root.background_color, factor);
+        if background.0 != new_background {
+            background.0 = new_background;
+        }
+        if let Some(mut border_color) = border_color {
+            let new_border = fade_color(root.border_color, factor);
+            if border_color.0 != new_border {
+                border_color.0 = new_border;
+            }
+        }
+
+        for e in q_children.iter_descendants(e_root) {
+            let Ok((text_color, bg_color, tracker)) = q_node.get_mut(e) else {
+                continue;
+            };
+            let mut new_tracker = PerfUiFadeTracker::default();
+            let old_tracker = tracker.as_deref();
+            if let Some(mut text_color) = text_color {
+                let base = match old_tracker.and_then(|t| t.text) {
+                    Some((base, faded)) if faded == text_color.0 => base,
+                    _ => text_color.0,
+                };
+                let faded = fade_color(base, factor);
+                if text_color.0 != faded {
+                    text_color.0 = faded;
+                }
+                new_tracker.text = Some((base, faded));
+            }
+            if let Some(mut bg_color) = bg_color {
+                let base = match old_tracker.and_then(|t| t.background) {
+                    Some((base, faded)) if faded == bg_color.0 => base,
+                    _ => bg_color.0,
+                };
+                let faded = fade_color(base, factor);
+                if bg_color.0 != faded {
+                    bg_color.0 = faded;
+                }
+                new_tracker.background = Some((base, faded));
+            }
+            if let Some(mut tracker) = tracker {
+                *tracker = new_tracker;
+            } else {
+                commands.entity(e).insert(new_tracker);
+            }
+        }
+    }
+}
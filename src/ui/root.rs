@@ -4,9 +4,15 @@
 //! and what manages the UI for all your entries.
 
 use bevy::prelude::*;
+use bevy::ui::widget::NodeImageMode;
+use bevy::utils::Duration;
+use bevy::window::{PrimaryWindow, WindowMode};
+
+use crate::PerfUiEnabled;
 
 /// Which corner of the screen to display the Perf UI at?
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PerfUiPosition {
     /// Absolute positioning based on distance from top and left edges of viewport.
     TopLeft,
@@ -41,11 +47,34 @@ pub enum PerfUiPosition {
 /// We will automatically detect that you have added these components
 /// and will do the rest of the setup to spawn the UI. :)
 #[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PerfUiRoot {
     /// The color to use for the background of the Perf UI.
     ///
     /// Default: BLACK with alpha 0.5
     pub background_color: Color,
+    /// An image to display as the background of the Perf UI.
+    ///
+    /// It is drawn over `background_color`, so you might want to set that
+    /// to `Color::NONE`, if the image has transparent parts.
+    ///
+    /// Default: `None` (only `background_color`)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub background_image: Option<Handle<Image>>,
+    /// How to fit `background_image` to the size of the Perf UI.
+    ///
+    /// Use `NodeImageMode::Sliced` for a nine-patch image, so the corners
+    /// and borders are not stretched. The image never affects the size of
+    /// the Perf UI, so `NodeImageMode::Auto` behaves like `Stretch`.
+    ///
+    /// Default: `NodeImageMode::Stretch`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub background_image_mode: NodeImageMode,
+    /// The color to multiply `background_image` with.
+    ///
+    /// Default: WHITE (the image as it is)
+    pub background_image_tint: Color,
     /// The color to use for the background of each entry/row.
     ///
     /// Default: NONE
@@ -59,10 +88,30 @@ pub struct PerfUiRoot {
     ///
     /// Default: `true`
     pub display_labels: bool,
+    /// Display the value before the label in each row (like "144 FPS").
+    ///
+    /// The ": " after the label is omitted. Each label is placed right
+    /// after its value, so the values column is now the one at the left
+    /// edge. Set `values_col_width` to make the labels line up vertically,
+    /// otherwise their position depends on the width of each value.
+    ///
+    /// Default: `false`
+    pub value_first: bool,
     /// Display entries horizontally instead of vertically.
     ///
     /// Default: `false`
     pub layout_horizontal: bool,
+    /// Arrange the entries into this many columns, side by side.
+    ///
+    /// The entries are split as evenly as possible, in sort order:
+    /// the first column gets the first few entries, and so on.
+    /// Within each column, all rows have the same width, so the
+    /// values stay aligned.
+    ///
+    /// Ignored if `layout_horizontal` is enabled.
+    ///
+    /// Default: `1`
+    pub columns: usize,
     /// The text to display if a value cannot be obtained.
     ///
     /// Default: `"N/A"`
@@ -75,15 +124,43 @@ pub struct PerfUiRoot {
     ///
     /// Default: GRAY
     pub default_value_color: Color,
+    /// The color for good values, for entries that color their values with a gradient.
+    ///
+    /// Replaces the color at the good end of each entry's color gradient,
+    /// unless the entry has its own `color_good`.
+    ///
+    /// Default: `None` (keep the gradients' own colors)
+    pub color_good: Option<Color>,
+    /// The color for bad values, for entries that color their values with a gradient.
+    ///
+    /// Replaces the color at the bad end of each entry's color gradient,
+    /// unless the entry has its own `color_bad`.
+    ///
+    /// Default: `None` (keep the gradients' own colors)
+    pub color_bad: Option<Color>,
     /// The color to use for label text.
     ///
+    /// Independent of the colors of the values (`default_value_color`,
+    /// color gradients, etc.). For example, set it to a muted gray to
+    /// make the values stand out more.
+    ///
     /// Default: WHITE
     pub label_color: Color,
+    /// Truncate labels longer than this many characters, ending them with "…".
+    ///
+    /// Characters are counted as grapheme clusters, so multibyte
+    /// characters are never cut half-way.
+    ///
+    /// Default: `None` (never truncate)
+    pub max_label_chars: Option<usize>,
     /// The font to use for labels.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub font_label: Handle<Font>,
     /// The font to use for values.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub font_value: Handle<Font>,
     /// The font to use for highlighted values.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub font_highlight: Handle<Font>,
     /// The font size for labels.
     ///
@@ -93,18 +170,52 @@ pub struct PerfUiRoot {
     ///
     /// Default: `18.0`
     pub fontsize_value: f32,
-    /// The ZIndex of the UI.
+    /// The global ZIndex of the UI.
     ///
-    /// Default: `i32::MAX` (display on top of all other UI)
-    pub z_index: GlobalZIndex,
+    /// Controls whether the Perf UI is displayed above or below other UI
+    /// in your app (across all UI hierarchies). Lower it if you want some
+    /// of your own UI to be displayed on top of the Perf UI.
+    ///
+    /// If `None`, no `GlobalZIndex` is used, and the Perf UI is ordered
+    /// like any other UI root.
+    ///
+    /// Default: `Some(GlobalZIndex(i32::MAX))` (display on top of all other UI)
+    #[cfg_attr(feature = "serde", serde(with = "crate::layout::serde_global_z_index"))]
+    pub z_index: Option<GlobalZIndex>,
     /// The position of the UI.
     ///
     /// Default: top-right corner
     pub position: PerfUiPosition,
+    /// Display the Perf UI inside one of your own UI nodes, instead of
+    /// at a corner of the screen.
+    ///
+    /// The Perf UI entity is made a child of this entity, and is laid out
+    /// like any other child node (such as a flex item), so it follows the
+    /// layout of your UI. `position`, `margin` and `margin_sides` are ignored.
+    /// Consider also setting `z_index` to `None`, so it is ordered like the
+    /// rest of your UI.
+    ///
+    /// If the parent is despawned (recursively), the Perf UI is despawned too.
+    /// Setting this back to `None` moves the Perf UI out of the node again.
+    ///
+    /// Default: `None` (a separate UI root)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub parent: Option<Entity>,
     /// Distance from the edge of the screen in pixels
     ///
     /// Default: `16.0`
     pub margin: f32,
+    /// Distance from each edge of the screen, instead of `margin`.
+    ///
+    /// Only the sides of the corner in `position` are used. For example,
+    /// with `PerfUiPosition::TopRight`, `top` and `right` set how far the
+    /// Perf UI is from the top and right edges. Useful to avoid overlapping
+    /// other HUD elements in the same corner.
+    ///
+    /// Pixel values are multiplied by `ui_scale`.
+    ///
+    /// Default: `None` (use `margin` for all sides)
+    pub margin_sides: Option<UiRect>,
     /// Empty space around the edge of the Perf UI
     ///
     /// Default: `2.0`
@@ -117,62 +228,352 @@ pub struct PerfUiRoot {
     ///
     /// Default: `0.0`
     pub inner_padding: f32,
+    /// Minimum empty space between the label and the value in every row
+    ///
+    /// Rows are stretched to the width of the Perf UI, so in wide
+    /// layouts, the actual space can be larger than this.
+    ///
+    /// Pixel values are multiplied by `ui_scale`.
+    ///
+    /// Default: `Val::Px(0.0)`
+    pub label_value_gap: Val,
+    /// Minimum width of the labels column
+    ///
+    /// If `Some`, rows are stretched to the width of the Perf UI, with the
+    /// label on the left and the value on the right, so that values line up
+    /// vertically. The labels column is at least this wide.
+    ///
+    /// If `None`, labels shrink to fit their text, and every value is placed
+    /// immediately after its label. This gives the most compact layout, but
+    /// the values of different rows (with different label lengths) will no
+    /// longer line up.
+    ///
+    /// Pixel values are multiplied by `ui_scale`.
+    ///
+    /// Default: `Some(Val::Auto)`
+    pub label_min_width: Option<Val>,
+    /// Multiplier for all sizes (font sizes, margins, paddings, etc.)
+    ///
+    /// Useful if you want the Perf UI to be bigger or smaller than
+    /// the rest of your UI. Note that Bevy UI sizes are already in
+    /// logical pixels, so the Perf UI will automatically follow
+    /// the scale factor (DPI) of the window, and Bevy's `UiScale`
+    /// (see the `ui_scale` example).
+    ///
+    /// Default: `1.0`
+    pub ui_scale: f32,
+    /// Border around the edge of the Perf UI
+    ///
+    /// Default: `UiRect::ZERO` (no border)
+    pub border: UiRect,
+    /// The color of the border
+    ///
+    /// Default: `Color::NONE`
+    pub border_color: Color,
+    /// Text justification for the values
+    ///
+    /// Applies within the values column. Most noticeable if you set `values_col_width`.
+    ///
+    /// Default: `JustifyText::Right`
+    pub value_justify: JustifyText,
     /// Force a fixed width (in pixels) for the values column
     ///
     /// Default: `None`
     pub values_col_width: Option<f32>,
+    /// Force a fixed width for the whole Perf UI
+    ///
+    /// Useful for consistent screenshots: the Perf UI will have the same
+    /// width, regardless of its contents. Values stay aligned to the right.
+    ///
+    /// Content that does not fit is clipped. Labels that are too long are
+    /// cut off, instead of making the Perf UI wider. Set `max_label_chars`
+    /// to shorten them with an ellipsis instead.
+    ///
+    /// Pixel values are multiplied by `ui_scale`.
+    ///
+    /// Default: `None` (fit the contents)
+    pub width: Option<Val>,
+    /// Lock the width of each value after this many updates.
+    ///
+    /// During the warm-up, the widest each value has been is tracked.
+    /// After that, the value is fixed to that width, so that the layout
+    /// never changes again. Values that later become wider are clipped.
+    ///
+    /// Entries update every frame, unless throttled. The warm-up starts
+    /// over whenever the Perf UI is rebuilt (such as when the `PerfUiRoot`
+    /// is changed). Only supported by simple entries (not bars or other widgets).
+    ///
+    /// Default: `None` (the width follows the value)
+    pub lock_widths_after: Option<u32>,
+    /// Fade the Perf UI when the user is idle: `(delay, alpha)`.
+    ///
+    /// If there has been no input (keyboard, mouse, touch) for longer than
+    /// `delay`, the Perf UI fades out until its opacity is multiplied by `alpha`.
+    /// Any input immediately restores full opacity.
+    ///
+    /// Default: `None` (never fade)
+    pub idle_fade: Option<(Duration, f32)>,
+    /// Allow clicking on a value to copy it.
+    ///
+    /// Clicking sends a [`PerfUiCopyEvent`](crate::ui::copy::PerfUiCopyEvent),
+    /// which you can use to put the text into the clipboard. With the
+    /// `clipboard` cargo feature, this is done for you. See the [`copy`](crate::ui::copy) module for more info.
+    ///
+    /// Only supported by simple entries (not bars or other widgets).
+    ///
+    /// Default: `false`
+    pub click_to_copy: bool,
+    /// Hide the Perf UI while the primary window is in exclusive fullscreen.
+    ///
+    /// Useful when recording gameplay. Only `WindowMode::Fullscreen` and
+    /// `WindowMode::SizedFullscreen` count as exclusive fullscreen.
+    /// In `WindowMode::BorderlessFullscreen`, the Perf UI stays visible.
+    ///
+    /// The root entity's `Visibility` is restored when the window leaves
+    /// exclusive fullscreen.
+    ///
+    /// Default: `false`
+    pub auto_hide_fullscreen: bool,
+    /// Collapse the Perf UI, hiding all entries except `collapsed_summary`.
+    ///
+    /// Hidden entries are paused (their widgets are not updated), and
+    /// resume as soon as the Perf UI is expanded again.
+    ///
+    /// Default: `false`
+    pub collapsed: bool,
+    /// The label of the entry to keep displaying while `collapsed`.
+    ///
+    /// Useful for a compact always-on badge (like just the FPS).
+    /// The entry keeps updating while the Perf UI is collapsed.
+    /// If `None`, or no entry has this label, nothing is displayed
+    /// while collapsed, except the background.
+    ///
+    /// Default: `None`
+    pub collapsed_summary: Option<String>,
+    /// Split the entries into pages of this many entries, and only
+    /// display one page at a time.
+    ///
+    /// The entries are split in sort order. A page indicator (like "2/3")
+    /// is displayed after the entries. Useful on small screens, like handhelds.
+    /// Entries on other pages are paused (their widgets are not updated).
+    ///
+    /// The current page is stored in the [`PerfUiPage`] component, on the
+    /// same entity. Changing it does not rebuild the Perf UI.
+    ///
+    /// Default: `None` (display all entries)
+    pub page_size: Option<usize>,
+    /// Key to cycle to the next page, if `page_size` is set.
+    ///
+    /// After the last page, it wraps around to the first page.
+    ///
+    /// Default: `Some(KeyCode::PageDown)`
+    pub page_key: Option<KeyCode>,
+    /// Key to clear the worst values held by entries (such as
+    /// `PerfUiEntryFrameTime` with `latch_max`), so they start over.
+    ///
+    /// Clears the entries of all Perf UIs, not only this one.
+    ///
+    /// Default: `Some(KeyCode::Delete)`
+    pub latch_clear_key: Option<KeyCode>,
 }
 
+/// The page currently displayed by a Perf UI, if its `page_size` is set.
+///
+/// Inserted automatically when the page is changed using the `page_key`.
+/// Insert or modify it to change the page from code. If it is past the
+/// last page, it wraps around. Without it, the first page is displayed.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PerfUiPage(pub usize);
+
 impl Default for PerfUiRoot {
     fn default() -> Self {
         PerfUiRoot {
             background_color: Color::srgba(0.0, 0.0, 0.0, 0.5),
+            background_image: None,
+            background_image_mode: NodeImageMode::Stretch,
+            background_image_tint: Color::WHITE,
             inner_background_color: Color::NONE,
             inner_background_color_highlight: Color::srgba(1.0, 0.0, 0.0, 1.0 / 16.0),
             display_labels: true,
+            value_first: false,
             layout_horizontal: false,
+            columns: 1,
             text_err: "N/A".into(),
             err_color: Color::srgb(0.5, 0.5, 0.5),
             default_value_color: Color::srgb(0.75, 0.75, 0.75),
+            color_good: None,
+            color_bad: None,
             label_color: Color::srgb(1.0, 1.0, 1.0),
+            max_label_chars: None,
             font_label: default(),
             font_value: default(),
             font_highlight: default(),
             fontsize_label: 16.0,
             fontsize_value: 18.0,
-            z_index: GlobalZIndex(i32::MAX),
+            z_index: Some(GlobalZIndex(i32::MAX)),
             position: default(),
+            parent: None,
             margin: 16.0,
+            margin_sides: None,
             padding: 2.0,
             inner_margin: 0.0,
             inner_padding: 0.0,
+            label_value_gap: Val::Px(0.0),
+            label_min_width: Some(Val::Auto),
+            ui_scale: 1.0,
+            border: UiRect::ZERO,
+            border_color: Color::NONE,
+            value_justify: JustifyText::Right,
             values_col_width: None,
+            width: None,
+            lock_widths_after: None,
+            idle_fade: None,
+            click_to_copy: false,
+            auto_hide_fullscreen: false,
+            collapsed: false,
+            collapsed_summary: None,
+            page_size: None,
+            page_key: Some(KeyCode::PageDown),
+            latch_clear_key: Some(KeyCode::Delete),
+        }
+    }
+}
+
+impl PerfUiRoot {
+    /// How many pages the entries are split into (at least 1).
+    pub(crate) fn n_pages(&self, n_entries: usize) -> usize {
+        self.page_size
+            .map(|size| n_entries.div_ceil(size.max(1)))
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// Get a UI size value in pixels, multiplied by `ui_scale`.
+    ///
+    /// Useful when implementing custom widgets.
+    pub fn px(&self, px: f32) -> Val {
+        Val::Px(px * self.ui_scale)
+    }
+
+    fn use_grid(&self) -> bool {
+        self.columns > 1 && !self.layout_horizontal
+    }
+
+    fn display(&self) -> Display {
+        if self.use_grid() {
+            Display::Grid
+        } else {
+            Display::Flex
+        }
+    }
+
+    /// Compute the grid rows needed to split `n_entries` into our columns.
+    pub(crate) fn grid_rows(&self, n_entries: usize) -> Vec<RepeatedGridTrack> {
+        if self.use_grid() {
+            let rows = n_entries.div_ceil(self.columns).max(1);
+            RepeatedGridTrack::auto(rows as u16)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// The direction of the children (label, then value) of each row.
+    pub(crate) fn row_direction(&self) -> FlexDirection {
+        if self.value_first {
+            FlexDirection::RowReverse
+        } else {
+            FlexDirection::Row
+        }
+    }
+
+    /// How the label and value should be placed within each row.
+    pub(crate) fn row_justify(&self) -> JustifyContent {
+        if self.value_first {
+            // with `RowReverse`, this packs the value and label to the left
+            JustifyContent::FlexEnd
+        } else if self.label_min_width.is_some() {
+            JustifyContent::SpaceBetween
+        } else {
+            JustifyContent::FlexStart
+        }
+    }
+
+    /// The text to display for a label.
+    pub(crate) fn format_label(&self, label: &str) -> String {
+        let label = if let Some(max) = self.max_label_chars {
+            crate::utils::truncate_with_ellipsis(label, max)
+        } else {
+            label.to_owned()
+        };
+        if self.value_first {
+            label
+        } else {
+            format!("{}: ", label)
+        }
+    }
+
+    /// The min width of the wrapper node for labels.
+    pub(crate) fn label_min_width(&self) -> Val {
+        self.label_min_width
+            .map(|w| self.scale_val(w))
+            .unwrap_or(Val::Auto)
+    }
+
+    /// The overflow of the wrapper node for labels.
+    ///
+    /// With a fixed `width`, labels may shrink below the size of their text.
+    pub(crate) fn label_overflow(&self) -> Overflow {
+        if self.width.is_some() {
+            Overflow {
+                x: OverflowAxis::Hidden,
+                y: OverflowAxis::Visible,
+            }
+        } else {
+            Overflow::DEFAULT
+        }
+    }
+
+    /// How label text should be laid out.
+    ///
+    /// With a fixed `width`, labels are cut off rather than wrapped.
+    pub(crate) fn label_text_layout(&self) -> TextLayout {
+        if self.width.is_some() {
+            TextLayout::new_with_no_wrap()
+        } else {
+            TextLayout::default()
+        }
+    }
+
+    pub(crate) fn scale_val(&self, val: Val) -> Val {
+        match val {
+            Val::Px(px) => self.px(px),
+            other => other,
         }
     }
 }
 
 impl PerfUiPosition {
-    fn top(self, margin: f32) -> Val {
+    fn top(self, margin: Val) -> Val {
         match self {
-            PerfUiPosition::TopLeft | PerfUiPosition::TopRight => Val::Px(margin),
+            PerfUiPosition::TopLeft | PerfUiPosition::TopRight => margin,
             PerfUiPosition::BottomLeft | PerfUiPosition::BottomRight => Val::Auto,
         }
     }
-    fn bottom(self, margin: f32) -> Val {
+    fn bottom(self, margin: Val) -> Val {
         match self {
-            PerfUiPosition::BottomLeft | PerfUiPosition::BottomRight => Val::Px(margin),
+            PerfUiPosition::BottomLeft | PerfUiPosition::BottomRight => margin,
             PerfUiPosition::TopLeft | PerfUiPosition::TopRight => Val::Auto,
         }
     }
-    fn left(self, margin: f32) -> Val {
+    fn left(self, margin: Val) -> Val {
         match self {
-            PerfUiPosition::TopLeft | PerfUiPosition::BottomLeft => Val::Px(margin),
+            PerfUiPosition::TopLeft | PerfUiPosition::BottomLeft => margin,
             PerfUiPosition::TopRight | PerfUiPosition::BottomRight => Val::Auto,
         }
     }
-    fn right(self, margin: f32) -> Val {
+    fn right(self, margin: Val) -> Val {
         match self {
-            PerfUiPosition::TopRight | PerfUiPosition::BottomRight => Val::Px(margin),
+            PerfUiPosition::TopRight | PerfUiPosition::BottomRight => margin,
             PerfUiPosition::TopLeft | PerfUiPosition::BottomLeft => Val::Auto,
         }
     }
@@ -184,34 +585,175 @@ pub(crate) fn rc_setup_perf_ui(
     !q.is_empty()
 }
 
+/// Marker for a Perf UI that was made a child of its `PerfUiRoot::parent`.
+#[derive(Component)]
+pub(crate) struct PerfUiParented;
+
 pub(crate) fn setup_perf_ui(
     mut commands: Commands,
-    mut q_root: Query<(Entity, &PerfUiRoot, Option<&mut BackgroundColor>, Option<&mut Node>), Changed<PerfUiRoot>>,
+    mut q_root: Query<(
+        Entity,
+        &PerfUiRoot,
+        Option<&mut BackgroundColor>,
+        Option<&mut BorderColor>,
+        Option<&mut Node>,
+        Option<&Parent>,
+        Has<PerfUiParented>,
+    ), Changed<PerfUiRoot>>,
 ) {
-    for (e, perf_ui, background, style) in &mut q_root {
-        let new_style = Node {
-            position_type: PositionType::Absolute,
-            top: perf_ui.position.top(perf_ui.margin),
-            bottom: perf_ui.position.bottom(perf_ui.margin),
-            left: perf_ui.position.left(perf_ui.margin),
-            right: perf_ui.position.right(perf_ui.margin),
+    for (e, perf_ui, background, border_color, style, current_parent, parented) in &mut q_root {
+        match perf_ui.parent {
+            Some(e_parent) => {
+                if current_parent.map(|p| p.get()) != Some(e_parent) {
+                    commands.entity(e_parent).add_child(e);
+                }
+                commands.entity(e).insert(PerfUiParented);
+            }
+            // only undo what we did, in case the user has
+            // put the Perf UI in their hierarchy themselves
+            None if parented => {
+                commands.entity(e)
+                    .remove_parent()
+                    .remove::<PerfUiParented>();
+            }
+            None => {}
+        }
+        let margin = perf_ui.margin_sides.unwrap_or(UiRect::all(Val::Px(perf_ui.margin)));
+        let (position_type, top, bottom, left, right) = if perf_ui.parent.is_some() {
+            (PositionType::Relative, Val::Auto, Val::Auto, Val::Auto, Val::Auto)
+        } else {
+            (
+                PositionType::Absolute,
+                perf_ui.position.top(perf_ui.scale_val(margin.top)),
+                perf_ui.position.bottom(perf_ui.scale_val(margin.bottom)),
+                perf_ui.position.left(perf_ui.scale_val(margin.left)),
+                perf_ui.position.right(perf_ui.scale_val(margin.right)),
+            )
+        };
+        let mut new_style = Node {
+            display: perf_ui.display(),
+            position_type,
+            top,
+            bottom,
+            left,
+            right,
             flex_direction: if perf_ui.layout_horizontal {
                 FlexDirection::Row
             } else {
                 FlexDirection::Column
             },
             align_items: AlignItems::Stretch,
-            padding: UiRect::all(Val::Px(perf_ui.padding)),
+            grid_auto_flow: GridAutoFlow::Column,
+            grid_template_columns: if perf_ui.use_grid() {
+                RepeatedGridTrack::auto(perf_ui.columns as u16)
+            } else {
+                Vec::new()
+            },
+            width: perf_ui.width
+                .map(|w| perf_ui.scale_val(w))
+                .unwrap_or(Val::Auto),
+            overflow: if perf_ui.width.is_some() {
+                Overflow::clip()
+            } else {
+                Overflow::DEFAULT
+            },
+            padding: UiRect::all(perf_ui.px(perf_ui.padding)),
+            border: UiRect {
+                left: perf_ui.scale_val(perf_ui.border.left),
+                right: perf_ui.scale_val(perf_ui.border.right),
+                top: perf_ui.scale_val(perf_ui.border.top),
+                bottom: perf_ui.scale_val(perf_ui.border.bottom),
+            },
             ..default()
         };
-        if let (Some(mut background), Some(mut style)) = (background, style) {
+        if let (Some(mut background), Some(mut border_color), Some(mut style)) = (background, border_color, style) {
             background.0 = perf_ui.background_color;
+            border_color.0 = perf_ui.border_color;
+            // the rows depend on the number of entries,
+            // they are computed when sorting the entries
+            if perf_ui.use_grid() {
+                new_style.grid_template_rows = std::mem::take(&mut style.grid_template_rows);
+            }
             *style = new_style;
         } else {
             commands.entity(e).insert((
                 BackgroundColor(perf_ui.background_color),
+                BorderColor(perf_ui.border_color),
                 new_style
             ));
         }
+        if let Some(image) = &perf_ui.background_image {
+            let image_mode = match &perf_ui.background_image_mode {
+                // the image must not change the size of the Perf UI
+                NodeImageMode::Auto => NodeImageMode::Stretch,
+                other => other.clone(),
+            };
+            commands.entity(e).insert(ImageNode {
+                image: image.clone(),
+                color: perf_ui.background_image_tint,
+                image_mode,
+                ..default()
+            });
+        } else {
+            commands.entity(e).remove::<ImageNode>();
+        }
+        if let Some(z_index) = perf_ui.z_index {
+            commands.entity(e).insert(z_index);
+        } else {
+            commands.entity(e).remove::<GlobalZIndex>();
+        }
+    }
+}
+
+pub(crate) fn apply_perf_ui_enabled(
+    enabled: Res<PerfUiEnabled>,
+    mut q_root: Query<(&PerfUiRoot, &mut Node)>,
+) {
+    for (perf_ui, mut node) in &mut q_root {
+        node.display = if enabled.0 {
+            perf_ui.display()
+        } else {
+            Display::None
+        };
+    }
+}
+
+/// Remembers the visibility of a Perf UI that was hidden by `auto_hide_fullscreen`.
+#[derive(Component)]
+pub(crate) struct PerfUiHiddenFullscreen(Visibility);
+
+pub(crate) fn rc_any_auto_hide_fullscreen(
+    q_root: Query<&PerfUiRoot>,
+    q_hidden: Query<(), With<PerfUiHiddenFullscreen>>,
+) -> bool {
+    // also run if some are still hidden, so they can be restored
+    q_root.iter().any(|root| root.auto_hide_fullscreen) || !q_hidden.is_empty()
+}
+
+pub(crate) fn auto_hide_perf_ui_fullscreen(
+    mut commands: Commands,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mut q_root: Query<(Entity, &PerfUiRoot, &mut Visibility, Option<&PerfUiHiddenFullscreen>)>,
+) {
+    let exclusive = q_window.get_single()
+        .map(|window| matches!(
+            window.mode,
+            WindowMode::Fullscreen(_) | WindowMode::SizedFullscreen(_)
+        ))
+        .unwrap_or(false);
+    for (e, perf_ui, mut visibility, hidden) in &mut q_root {
+        let hide = exclusive && perf_ui.auto_hide_fullscreen;
+        match (hide, hidden) {
+            // if it is already hidden, leave it alone
+            (true, None) if *visibility != Visibility::Hidden => {
+                commands.entity(e).insert(PerfUiHiddenFullscreen(*visibility));
+                *visibility = Visibility::Hidden;
+            }
+            (false, Some(hidden)) => {
+                *visibility = hidden.0;
+                commands.entity(e).remove::<PerfUiHiddenFullscreen>();
+            }
+            _ => {}
+        }
     }
 }
@@ -5,11 +5,13 @@ use std::marker::PhantomData;
 use bevy::prelude::*;
 use bevy::ecs::system::SystemParam;
 use bevy::ecs::system::StaticSystemParam;
-use bevy::ecs::system::lifetimeless::SQuery;
+use bevy::ecs::system::lifetimeless::{SQuery, SRes};
 use crate::ui::root::PerfUiRoot;
-use crate::entry::PerfUiEntry;
+use crate::entry::{PerfUiEntry, PerfUiThrottle};
+use crate::metrics::PerfUiEntryOutput;
 
-use super::PerfUiSortKey;
+use super::{PerfUiSortKey, PerfUiWidgetLabel};
+use super::collapse::PerfUiWidgetCollapsed;
 
 /// Trait for Perf UI Widgets.
 pub trait PerfUiWidget<T: PerfUiEntry>: Component {
@@ -69,6 +71,29 @@ pub trait PerfUiWidget<T: PerfUiEntry>: Component {
 
     /// The sort key of the entry that the widget is displaying.
     fn sort_key(&self) -> i32;
+
+    /// Optional: the label of the entry that the widget is displaying.
+    ///
+    /// Used to find the widget for [`PerfUiRoot::collapsed_summary`].
+    /// If `None`, the widget is always hidden when the Perf UI is collapsed.
+    fn entry_label(&self) -> Option<&str> {
+        None
+    }
+
+    /// Optional: is the entry that the widget is displaying enabled?
+    ///
+    /// If `false`, the widget is not spawned.
+    fn entry_enabled(&self) -> bool {
+        true
+    }
+
+    /// Optional: update the widget less often than every frame.
+    ///
+    /// If this returns `Some`, `update` will only be called
+    /// when the throttle is due.
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        None
+    }
 }
 
 /// Marker component to keep track of a widget's toplevel entity
@@ -78,6 +103,10 @@ pub struct PerfUiWidgetMarker<W> {
     _pd: PhantomData<W>,
 }
 
+/// Marker for widgets that have been spawned, but not updated yet
+#[derive(Component)]
+pub(crate) struct PerfUiWidgetFresh;
+
 pub(crate) fn rc_setup_perf_ui_widget<E: PerfUiEntry, W: PerfUiWidget<E>>(
     q: Query<(), Or<(Changed<W>, Changed<PerfUiRoot>)>>,
     removed: RemovedComponents<W>,
@@ -123,6 +152,9 @@ pub(crate) fn setup_perf_ui_widget<E: PerfUiEntry, W: PerfUiWidget<E>>(
             commands.entity(e_widget).despawn_recursive();
         }
 
+        if !widget.entry_enabled() {
+            continue;
+        }
         let e_widget = widget.spawn(
             root, e_root, &mut commands, &mut widget_param
         );
@@ -131,8 +163,12 @@ pub(crate) fn setup_perf_ui_widget<E: PerfUiEntry, W: PerfUiWidget<E>>(
                 e_root,
                 _pd: PhantomData,
             },
+            PerfUiWidgetFresh,
             PerfUiSortKey(widget.sort_key()),
         ));
+        if let Some(label) = widget.entry_label() {
+            commands.entity(e_widget).insert(PerfUiWidgetLabel(label.to_owned()));
+        }
         commands.entity(e_root).add_child(e_widget);
     }
 }
@@ -142,31 +178,84 @@ pub(crate) fn setup_perf_ui_widget<E: PerfUiEntry, W: PerfUiWidget<E>>(
 /// Exposed as `pub` so you can refer to it for ordering.
 #[allow(private_interfaces)]
 pub fn update_perf_ui_widget<E: PerfUiEntry, W: PerfUiWidget<E>>(
+    time: Res<Time<Real>>,
     q_root: Query<(Entity, &PerfUiRoot, &W)>,
-    q_widget: Query<(Entity, &PerfUiWidgetMarker<W>)>,
+    q_widget: Query<(Entity, &PerfUiWidgetMarker<W>, Has<PerfUiWidgetFresh>), Without<PerfUiWidgetCollapsed>>,
     widget_param: StaticSystemParam<W::SystemParamUpdate>,
+    mut commands: Commands,
 ) {
     let mut widget_param = widget_param.into_inner();
-    for (e_widget, marker) in &q_widget {
+    for (e_widget, marker, fresh) in &q_widget {
         let Ok((e_root, root, widget)) = q_root.get(marker.e_root) else {
             continue; // TODO: should we panic here?
         };
+        if fresh {
+            // always update newly-spawned widgets, so they don't
+            // have to wait for their throttle to display a value
+            commands.entity(e_widget).remove::<PerfUiWidgetFresh>();
+        } else if let Some(throttle) = widget.throttle() {
+            if !throttle.is_due(time.elapsed(), time.delta()) {
+                continue;
+            }
+        }
         widget.update(root, e_root, e_widget, &mut widget_param);
     }
 }
 
+/// How much to dim stale values (multiplier for the alpha of their color).
+const STALE_ALPHA: f32 = 0.4;
+
 #[doc(hidden)]
 #[derive(Component)]
 pub struct SimpleWidgetTextMarker<E: PerfUiEntry> {
+    part: usize,
     _pd: PhantomData<E>,
 }
 
+/// Keeps track of the widest a simple widget's value has been, for `lock_widths_after`.
+#[doc(hidden)]
+#[derive(Component, Default)]
+pub struct SimpleWidgetWidthLock {
+    updates: u32,
+    /// In logical pixels.
+    max_width: f32,
+    locked: bool,
+}
+
+impl SimpleWidgetWidthLock {
+    fn update(&mut self, after: u32, node: &mut Node, computed: &ComputedNode) {
+        if self.locked {
+            return;
+        }
+        // the layout lags one frame behind the text, so we measure the
+        // previous value; until the first layout, there is nothing to measure
+        if computed.size().x <= 0.0 {
+            return;
+        }
+        self.max_width = self.max_width.max(computed.size().x * computed.inverse_scale_factor());
+        self.updates += 1;
+        if self.updates >= after.max(1) {
+            self.locked = true;
+            node.min_width = Val::Px(self.max_width);
+            node.max_width = Val::Px(self.max_width);
+            node.overflow = Overflow::clip_x();
+        }
+    }
+}
+
 impl<E: PerfUiEntry> PerfUiWidget<E> for E {
     type SystemParamSpawn = ();
     type SystemParamUpdate = (
-        <E as PerfUiEntry>::SystemParam,
+        SQuery<&'static PerfUiEntryOutput<E>>,
+        SRes<Time<Real>>,
         SQuery<&'static mut BackgroundColor, With<PerfUiWidgetMarker<E>>>,
-        SQuery<(&'static mut Text, &'static mut TextColor, &'static mut TextFont), With<SimpleWidgetTextMarker<E>>>,
+        SQuery<(
+            &'static SimpleWidgetTextMarker<E>,
+            &'static mut Text,
+            &'static mut TextColor,
+            &'static mut TextFont,
+            Option<(&'static mut SimpleWidgetWidthLock, &'static mut Node, &'static ComputedNode)>,
+        )>,
     );
 
     fn spawn(
@@ -177,29 +266,33 @@ impl<E: PerfUiEntry> PerfUiWidget<E> for E {
         _: &mut <Self::SystemParamSpawn as SystemParam>::Item<'_, '_>,
     ) -> Entity {
         let e_widget = commands.spawn((
-            BackgroundColor(root.inner_background_color),
+            BackgroundColor(self.row_background().unwrap_or(root.inner_background_color)),
             Node {
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceBetween,
+                flex_direction: root.row_direction(),
+                justify_content: root.row_justify(),
                 align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(root.inner_margin)),
-                padding: UiRect::all(Val::Px(root.inner_padding)),
+                margin: UiRect::all(root.px(root.inner_margin)),
+                padding: UiRect::all(root.px(root.inner_padding)),
+                column_gap: root.scale_val(root.label_value_gap),
                 ..default()
             },
         )).id();
         if root.display_labels {
             let e_label_wrapper = commands.spawn((
                 Node {
-                    padding: UiRect::all(Val::Px(4.0)),
+                    padding: UiRect::all(root.px(4.0)),
+                    min_width: root.label_min_width(),
+                    overflow: root.label_overflow(),
                     ..default()
                 },
             )).id();
             let e_label = commands.spawn((
-                Text(format!("{}: ", self.label())),
+                Text(root.format_label(self.label())),
+                root.label_text_layout(),
                 TextColor(root.label_color),
                 TextFont {
                     font: root.font_label.clone(),
-                    font_size: root.fontsize_label,
+                    font_size: root.fontsize_label * root.ui_scale,
                     ..default()
                 },
             )).id();
@@ -208,29 +301,49 @@ impl<E: PerfUiEntry> PerfUiWidget<E> for E {
         }
         let e_text_wrapper = commands.spawn((
             Node {
-                padding: UiRect::all(Val::Px(4.0)),
+                padding: UiRect::all(root.px(4.0)),
                 width: if let Some(w) = root.values_col_width {
-                    Val::Px(w)
+                    root.px(w)
                 } else {
                     Val::Auto
                 },
                 justify_content: JustifyContent::FlexEnd,
+                column_gap: root.px(8.0),
                 ..default()
             },
         )).id();
-        let e_text = commands.spawn((
-            SimpleWidgetTextMarker::<E> {
-                _pd: PhantomData,
-            },
-            Text(root.text_err.clone()),
-            TextFont {
-                font: root.font_value.clone(),
-                font_size: root.fontsize_value,
-                ..default()
-            },
-            TextColor(root.err_color),
-        )).id();
-        commands.entity(e_text_wrapper).add_child(e_text);
+        if root.click_to_copy {
+            commands.entity(e_text_wrapper).insert((
+                crate::ui::copy::PerfUiCopyTarget::new(self.label()),
+                Interaction::default(),
+                BackgroundColor(Color::NONE),
+            ));
+        }
+        for part in 0..self.value_parts().max(1) {
+            let e_text = commands.spawn((
+                SimpleWidgetTextMarker::<E> {
+                    part,
+                    _pd: PhantomData,
+                },
+                Node {
+                    // fill the values column, so that `value_justify` has space to work with
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                Text(root.text_err.clone()),
+                TextLayout::new_with_justify(root.value_justify),
+                TextFont {
+                    font: root.font_value.clone(),
+                    font_size: root.fontsize_value * root.ui_scale,
+                    ..default()
+                },
+                TextColor(root.err_color),
+            )).id();
+            if root.lock_widths_after.is_some() {
+                commands.entity(e_text).insert(SimpleWidgetWidthLock::default());
+            }
+            commands.entity(e_text_wrapper).add_child(e_text);
+        }
         commands.entity(e_widget).add_child(e_text_wrapper);
         e_widget
     }
@@ -238,36 +351,53 @@ impl<E: PerfUiEntry> PerfUiWidget<E> for E {
     fn update(
         &self,
         root: &crate::prelude::PerfUiRoot,
-        _e_root: Entity,
+        e_root: Entity,
         e_widget: Entity,
         (
-            entry_param,
+            q_output,
+            time,
             q_widget,
             q_text,
         ): &mut <Self::SystemParamUpdate as SystemParam>::Item<'_, '_>,
     ) {
-        for (mut text, mut color, mut font) in q_text.iter_mut() {
-            let mut entry_highlight = false;
-            if let Some(value) = self.update_value(entry_param) {
-                let new_color = self.value_color(&value)
+        // the entry was already evaluated this frame, by `collect_perf_ui_metrics`
+        let output = q_output.get(e_root).ok();
+        let parts = output.and_then(|output| output.parts.as_ref());
+        let entry_highlight = parts.is_some() && output.is_some_and(|output| output.highlight);
+        let stale = output.is_some_and(|output| output.is_stale(self, time.elapsed()));
+        if let Ok(mut entry_bgcolor) = q_widget.get_mut(e_widget) {
+            if entry_highlight {
+                entry_bgcolor.0 = root.inner_background_color_highlight;
+            } else {
+                entry_bgcolor.0 = self.row_background()
+                    .unwrap_or(root.inner_background_color);
+            }
+        }
+        for (marker, mut text, mut color, mut font, width_lock) in q_text.iter_mut() {
+            if let (Some((mut lock, mut node, computed)), Some(after)) = (width_lock, root.lock_widths_after) {
+                lock.update(after, &mut node, computed);
+            }
+            let width_hint = self.width_hint_part(marker.part);
+            if let (Some(output), Some(parts)) = (output, parts) {
+                let mut new_color = output.color
                     .unwrap_or(root.default_value_color);
-                let s = self.format_value(&value);
-                let width_hint = self.width_hint();
+                if stale {
+                    new_color = new_color.with_alpha(new_color.alpha() * STALE_ALPHA);
+                }
+                let s = parts.get(marker.part).cloned().unwrap_or_default();
                 *text = if s.len() < width_hint {
                     Text(format!("{:>w$}", s, w = width_hint))
                 } else {
                     Text(s)
                 };
                 *color = TextColor(new_color);
-                if self.value_highlight(&value) {
+                if entry_highlight {
                     font.font = root.font_highlight.clone();
-                    entry_highlight = true;
                 } else {
                     font.font = root.font_value.clone();
                 }
             } else {
                 let s = root.text_err.clone();
-                let width_hint = self.width_hint();
                 *text = if s.len() < width_hint {
                     Text(format!("{:>w$}", s, w = width_hint))
                 } else {
@@ -276,17 +406,132 @@ impl<E: PerfUiEntry> PerfUiWidget<E> for E {
                 *color = TextColor(root.err_color);
                 font.font = root.font_value.clone();
             }
-            if let Ok(mut entry_bgcolor) = q_widget.get_mut(e_widget) {
-                if entry_highlight {
-                    entry_bgcolor.0 = root.inner_background_color_highlight;
-                } else {
-                    entry_bgcolor.0 = root.inner_background_color;
-                }
-            }
         }
     }
 
     fn sort_key(&self) -> i32 {
         PerfUiEntry::sort_key(self)
     }
+
+    fn entry_label(&self) -> Option<&str> {
+        Some(PerfUiEntry::label(self))
+    }
+
+    fn entry_enabled(&self) -> bool {
+        PerfUiEntry::enabled(self)
+    }
+
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        PerfUiEntry::throttle(self)
+    }
+}
+
+#[cfg(all(test, feature = "entries"))]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy::utils::Duration;
+
+    use crate::prelude::*;
+    use super::SimpleWidgetTextMarker;
+
+    /// The displayed text and color of the (only) simple widget for `E`.
+    fn displayed<E: crate::entry::PerfUiEntry>(world: &mut World) -> (String, Color) {
+        let (_, text, color) = world
+            .query::<(&SimpleWidgetTextMarker<E>, &Text, &TextColor)>()
+            .single(world);
+        (text.0.trim().to_owned(), color.0)
+    }
+
+    /// The displayed text and alpha of the (only) simple widget for `E`.
+    fn displayed_alpha<E: crate::entry::PerfUiEntry>(world: &mut World) -> (String, f32) {
+        let (text, color) = displayed::<E>(world);
+        (text, color.alpha())
+    }
+
+    #[test]
+    fn values_become_stale_when_not_updated() {
+        let mut app = crate::utils::test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(250)));
+        let e_entry = app.world_mut().spawn(PerfUiEntryManual {
+            stale_after: Some(Duration::from_secs(1)),
+            ..PerfUiEntryManual::new("Manual")
+        }).id();
+        let set_value = |app: &mut App, value: Option<f64>| {
+            let entry = app.world().get::<PerfUiEntryManual>(e_entry).unwrap();
+            match value {
+                Some(value) => entry.set_value(value),
+                None => entry.clear_value(),
+            }
+        };
+
+        set_value(&mut app, Some(5.0));
+        app.update();
+        app.update();
+        let (text, fresh_alpha) = displayed_alpha::<PerfUiEntryManual>(app.world_mut());
+        assert_eq!(text, "5.00");
+
+        // the value stops updating: it is still displayed, and fresh for a while…
+        set_value(&mut app, None);
+        app.update();
+        app.update();
+        assert_eq!(displayed_alpha::<PerfUiEntryManual>(app.world_mut()), ("5.00".to_owned(), fresh_alpha));
+
+        // …until `stale_after` has passed
+        for _ in 0..4 {
+            app.update();
+        }
+        let (text, stale_alpha) = displayed_alpha::<PerfUiEntryManual>(app.world_mut());
+        assert_eq!(text, "5.00");
+        assert!(stale_alpha < fresh_alpha);
+
+        // a new value is fresh again
+        set_value(&mut app, Some(6.0));
+        app.update();
+        assert_eq!(displayed_alpha::<PerfUiEntryManual>(app.world_mut()), ("6.00".to_owned(), fresh_alpha));
+    }
+
+    #[test]
+    fn entry_colors_override_root_colors() {
+        let root_good = Color::srgb(0.0, 0.0, 1.0);
+        let entry_good = Color::srgb(1.0, 0.0, 1.0);
+        let mut app = crate::utils::test_app();
+        let entry = PerfUiEntryManual {
+            color_gradient: ColorGradient::new_preset_gyr(1.0, 2.0, 3.0).unwrap(),
+            color_good: Some(entry_good),
+            ..PerfUiEntryManual::new("Manual")
+        };
+        // at the good end of the gradient
+        entry.set_value(0.0);
+        let e_entry = app.world_mut().spawn((
+            PerfUiRoot {
+                color_good: Some(root_good),
+                ..default()
+            },
+            entry,
+        )).id();
+        let displayed_color = |app: &mut App| {
+            app.update();
+            app.update();
+            displayed::<PerfUiEntryManual>(app.world_mut()).1.to_srgba()
+        };
+        let assert_color = |displayed: Srgba, expected: Color| {
+            let expected = expected.to_srgba();
+            assert!(
+                (displayed.red - expected.red).abs() < 1e-3
+                    && (displayed.green - expected.green).abs() < 1e-3
+                    && (displayed.blue - expected.blue).abs() < 1e-3,
+                "displayed {displayed:?}, expected {expected:?}",
+            );
+        };
+
+        // the entry's own color wins over the root's…
+        assert_color(displayed_color(&mut app), entry_good);
+        // …which is used if the entry has none
+        app.world_mut().get_mut::<PerfUiEntryManual>(e_entry).unwrap().color_good = None;
+        assert_color(displayed_color(&mut app), root_good);
+        // without either, the gradient's own color is used
+        app.world_mut().get_mut::<PerfUiRoot>(e_entry).unwrap().color_good = None;
+        assert_color(displayed_color(&mut app), Color::srgb(0.0, 1.0, 0.0));
+    }
 }
@@ -1,14 +1,19 @@
 //! Helper functions
 //!
 //! Mostly stuff for implementing new `PerfUiEntry` types and
-//! formatting of values.
+//! formatting of values. The formatting functions live in
+//! [`format`](crate::format), and are re-exported here.
 
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicI32, Ordering};
 
 use bevy::prelude::*;
-use bevy::utils::Duration;
+use bevy::utils::{Duration, Instant};
 use bevy::math::FloatOrd;
 
+pub use crate::format::*;
+
 static NEXT_SORT_KEY: AtomicI32 = AtomicI32::new(1);
 
 /// Generate a new incrementally-increasing sort key.
@@ -30,10 +35,30 @@ pub fn next_sort_key() -> i32 {
 /// The interpolation is done in Bevy's OKLAB color space, so it looks
 /// nicer and more perceputally-uniform.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<(f32, Oklaba)>", into = "Vec<(f32, Oklaba)>"))]
 pub struct ColorGradient {
     stops: Vec<(FloatOrd, Oklaba)>,
 }
 
+impl From<Vec<(f32, Oklaba)>> for ColorGradient {
+    fn from(stops: Vec<(f32, Oklaba)>) -> Self {
+        let mut gradient = ColorGradient::new();
+        for (value, color) in stops {
+            gradient.add_stop(value, color.into());
+        }
+        gradient
+    }
+}
+
+impl From<ColorGradient> for Vec<(f32, Oklaba)> {
+    fn from(gradient: ColorGradient) -> Self {
+        gradient.stops.into_iter()
+            .map(|(value, color)| (value.0, color))
+            .collect()
+    }
+}
+
 impl ColorGradient {
     /// Create a new empty gradient.
     ///
@@ -60,6 +85,7 @@ impl ColorGradient {
     }
 
     /// Preset constructor: Red-Yellow-Green between the specified low-mid-high values.
+    #[allow(clippy::result_unit_err)]
     pub fn new_preset_ryg(low: f32, mid: f32, high: f32) -> Result<Self, ()> {
         if low.is_nan() || mid.is_nan() || high.is_nan() || low > mid || mid > high {
             return Err(());
@@ -74,6 +100,7 @@ impl ColorGradient {
     }
 
     /// Preset constructor: Green-Yellow-Red between the specified low-mid-high values.
+    #[allow(clippy::result_unit_err)]
     pub fn new_preset_gyr(low: f32, mid: f32, high: f32) -> Result<Self, ()> {
         if low.is_nan() || mid.is_nan() || high.is_nan() || low > mid || mid > high {
             return Err(());
@@ -183,6 +210,35 @@ impl ColorGradient {
         }
     }
 
+    /// Get the color for a value, with custom colors for the good and bad ends of the gradient.
+    ///
+    /// `goal` decides which end is which: with `MetricGoal::LowerIsBetter`, the first
+    /// (lowest) stop is the good end, and the last (highest) stop is the bad end.
+    /// If `good` or `bad` is `None`, the color of the stop at that end is kept.
+    pub fn get_color_for_value_with_ends(
+        &self,
+        value: f32,
+        goal: MetricGoal,
+        good: Option<Color>,
+        bad: Option<Color>,
+    ) -> Option<Color> {
+        if good.is_none() && bad.is_none() {
+            return self.get_color_for_value(value);
+        }
+        let (low, high) = match goal {
+            MetricGoal::LowerIsBetter => (good, bad),
+            MetricGoal::HigherIsBetter => (bad, good),
+        };
+        let mut gradient = self.clone();
+        if let (Some(color), Some((_, stop))) = (low, gradient.min_stop_mut()) {
+            *stop = color.into();
+        }
+        if let (Some(color), Some((_, stop))) = (high, gradient.max_stop_mut()) {
+            *stop = color.into();
+        }
+        gradient.get_color_for_value(value)
+    }
+
     /// Get the first (lowest) stop of the gradient
     pub fn min_stop(&self) -> Option<(&f32, &Oklaba)> {
         self.stops.first().map(|(f, c)| (&f.0, c))
@@ -218,125 +274,498 @@ impl ColorGradient {
     }
 }
 
-/// Format a float in a pretty way.
+/// Is a higher or a lower value better, for a given metric?
 ///
-/// - Right aligned
-/// - Padded with spaces to accomodate total width (digits + precision + decimal point (if any))
-/// - Clamped to all 9s if above digits count (example: `99.999` if digits = 2 and precision = 3)
-pub fn format_pretty_float(digits: u8, precision: u8, mut value: f64) -> String {
-    let digits = digits.max(1);
-    let max = 10.0f64.powi(digits as i32);
-
-    if value >= max {
-        value = max - 10.0f64.powi(-(precision as i32));
-    }
-
-    format!(
-        "{number:>width$.prec$}",
-        number = value,
-        width = width_hint_pretty_float(digits, precision),
-        prec = precision as usize,
-    )
+/// Used by entries to decide when a value crosses their highlight threshold.
+/// Note that the colors of a [`ColorGradient`] are not affected by this,
+/// as the gradient's stops already define which colors go with which values.
+/// It is only used to know which end of the gradient is "good", if its colors
+/// are replaced (see [`ColorGradient::get_color_for_value_with_ends`]).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MetricGoal {
+    /// Higher values are better (example: FPS).
+    ///
+    /// Values below the threshold are bad.
+    HigherIsBetter,
+    /// Lower values are better (example: frame time).
+    ///
+    /// Values above the threshold are bad.
+    #[default]
+    LowerIsBetter,
 }
 
-/// Width hint for a value formatted with `format_pretty_float`
-pub fn width_hint_pretty_float(digits: u8, precision: u8) -> usize {
-    if precision > 0 {
-        digits as usize + precision as usize + 1
-    } else {
-        digits as usize
+impl MetricGoal {
+    /// Check if the value is on the bad side of the threshold.
+    pub fn is_worse<T: PartialOrd>(self, value: T, threshold: T) -> bool {
+        match self {
+            MetricGoal::HigherIsBetter => value < threshold,
+            MetricGoal::LowerIsBetter => value > threshold,
+        }
     }
 }
 
-/// Format an integer in a pretty way.
+/// Helper for holding the peak (worst) value of a metric, with decay.
 ///
-/// - Right aligned
-/// - Padded with spaces to accomodate total width (digits)
-/// - Clamped to all 9s if above digits count (example: `99` if digits = 2)
-pub fn format_pretty_int(digits: u8, mut value: i64) -> String {
-    if value < 0 {
-        let digits = digits.max(2) - 1;
-        let max = 10i64.pow(digits as u32);
-        if -value >= max {
-            value = -(max - 1);
+/// Keeps displaying the worst value seen, until no worse value has been
+/// seen for a while. Then, eases back toward the current value.
+///
+/// Has interior mutability, so it can be updated from `PerfUiEntry::update_value`.
+/// Cloning gives a fresh (empty) `PeakHold`.
+#[derive(Debug, Default)]
+pub struct PeakHold {
+    state: Mutex<Option<PeakHoldState>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PeakHoldState {
+    peak: f32,
+    peak_time: Duration,
+    last_time: Duration,
+}
+
+impl Clone for PeakHold {
+    fn clone(&self) -> Self {
+        PeakHold::default()
+    }
+}
+
+impl PeakHold {
+    /// Update with the current value, and get the value to display.
+    ///
+    /// `now` is the current time. `hold` is how long to hold the peak for,
+    /// before starting to decay. The decay also uses `hold` as its time
+    /// constant: after each further `hold` duration, the distance to
+    /// the current value is reduced by roughly 63%.
+    ///
+    /// `goal` decides which values count as worse (a new peak).
+    pub fn update(&self, value: f32, now: Duration, hold: Duration, goal: MetricGoal) -> f32 {
+        let Ok(mut state) = self.state.lock() else {
+            return value;
+        };
+        let Some(st) = state.as_mut() else {
+            *state = Some(PeakHoldState {
+                peak: value,
+                peak_time: now,
+                last_time: now,
+            });
+            return value;
+        };
+        if !st.peak.is_finite() || !goal.is_worse(st.peak, value) {
+            // new peak (or the current value is equally bad)
+            st.peak = value;
+            st.peak_time = now;
+        } else if now.saturating_sub(st.peak_time) > hold {
+            let dt = now.saturating_sub(st.last_time).as_secs_f32();
+            let tau = hold.as_secs_f32();
+            let k = if tau > 0.0 { (-dt / tau).exp() } else { 0.0 };
+            st.peak = value + (st.peak - value) * k;
         }
-    } else {
-        let digits = digits.max(1);
-        let max = 10i64.pow(digits as u32);
-        if value >= max {
-            value = max - 1;
+        st.last_time = now;
+        st.peak
+    }
+
+    /// Forget the held peak.
+    pub fn reset(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = None;
         }
-    };
+    }
+}
+
+/// Helper for latching the worst value of a metric.
+///
+/// Unlike [`PeakHold`], the worst value is held forever (it never decays),
+/// until it is replaced by an even worse value, or [`reset`](Self::reset).
+///
+/// Has interior mutability, so it can be updated from `PerfUiEntry::update_value`.
+/// Cloning gives a fresh (empty) `LatchedPeak`.
+#[derive(Debug, Default)]
+pub struct LatchedPeak {
+    state: Mutex<Option<f64>>,
+}
 
-    format!(
-        "{number:>width$}",
-        number = value,
-        width = width_hint_pretty_int(digits),
-    )
+impl Clone for LatchedPeak {
+    fn clone(&self) -> Self {
+        LatchedPeak::default()
+    }
 }
 
-/// Width hint for a value formatted with `format_pretty_int`
-pub fn width_hint_pretty_int(digits: u8) -> usize {
-    digits as usize + 1
+impl LatchedPeak {
+    /// Update with the current value, and get the value to display.
+    ///
+    /// `goal` decides which values count as worse.
+    pub fn update(&self, value: f64, goal: MetricGoal) -> f64 {
+        let Ok(mut state) = self.state.lock() else {
+            return value;
+        };
+        match *state {
+            Some(peak) if peak.is_finite() && goal.is_worse(peak, value) => peak,
+            _ => {
+                *state = Some(value);
+                value
+            }
+        }
+    }
+
+    /// The latched value, if any (`None` before the first update, or after a `reset`).
+    pub fn get(&self) -> Option<f64> {
+        *self.state.lock().ok()?
+    }
+
+    /// Forget the latched value.
+    pub fn reset(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = None;
+        }
+    }
 }
 
-/// Format a time duration in a pretty way.
+/// How to smooth the values of an entry over time.
 ///
-/// See [`format_pretty_time_hms`].
+/// Smoothing is always configured per entry: there is no global setting
+/// that overrides it. Each entry keeps its own state, so entries with
+/// different settings can be displayed together. For example, an averaged
+/// FPS alongside the instantaneous frame time:
 ///
-/// - Clamped to all 9s if above max (example: `99:59:59.999`)
-pub fn format_pretty_time(precision: u8, value: Duration) -> String {
-    let max = 99 * 3600 + 59 * 60 + 59;
-    let secs = value.as_secs();
-    if secs > max {
-        if precision > 0 {
-            return format!("99:59:59.{dummy:9<prec$}", dummy = "", prec = precision as usize);
-        } else {
-            return "99:59:59".into()
+/// ```rust
+/// commands.spawn((
+///     PerfUiRoot::default(),
+///     PerfUiEntryFPS {
+///         smoothing: Smoothing::Window(60),
+///         ..default()
+///     },
+///     PerfUiEntryFrameTime {
+///         smoothed: false,
+///         ..default()
+///     },
+/// ));
+/// ```
+///
+/// For entries that read a Bevy diagnostic, the diagnostic's own smoothing
+/// (configured by the diagnostics plugin) is only used when the entry's
+/// `smoothed` is `true` and its `smoothing` is `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Smoothing {
+    /// No extra smoothing. The entry's own behavior is used.
+    #[default]
+    None,
+    /// Average of the last `n` raw values.
+    Window(usize),
+    /// Exponential moving average, with the given weight (`0.0..=1.0`) for each new value.
+    ///
+    /// Updated as `ema = ema * (1 - a) + value * a`. Only needs to remember
+    /// a single value. Higher weights respond faster to changes.
+    Ema(f64),
+}
+
+/// State for smoothing values using [`Smoothing`].
+///
+/// Has interior mutability, so it can be updated from `PerfUiEntry::update_value`.
+/// Cloning gives a fresh (empty) `Smoother`.
+#[derive(Debug, Default)]
+pub struct Smoother {
+    state: Mutex<SmootherState>,
+}
+
+#[derive(Debug, Default)]
+struct SmootherState {
+    smoothing: Smoothing,
+    last_time: Option<Instant>,
+    window: VecDeque<f64>,
+    output: f64,
+    sample_fill: Option<(usize, usize)>,
+}
+
+impl Clone for Smoother {
+    fn clone(&self) -> Self {
+        Smoother::default()
+    }
+}
+
+impl Smoother {
+    /// Update with a new raw value, and get the smoothed value.
+    ///
+    /// `time` is when the raw value was measured. If it is the same as
+    /// in the previous call, the value is not counted again, so it is fine
+    /// to call this more than once per measurement.
+    pub fn update(&self, smoothing: Smoothing, time: Instant, value: f64) -> f64 {
+        let Ok(mut state) = self.state.lock() else {
+            return value;
+        };
+        if state.smoothing != smoothing {
+            *state = SmootherState {
+                smoothing,
+                ..default()
+            };
+        }
+        if state.last_time == Some(time) {
+            return state.output;
+        }
+        let first = state.last_time.is_none();
+        state.last_time = Some(time);
+        state.output = match smoothing {
+            Smoothing::None => value,
+            Smoothing::Window(n) => {
+                state.window.push_back(value);
+                while state.window.len() > n.max(1) {
+                    state.window.pop_front();
+                }
+                state.sample_fill = Some((state.window.len(), n.max(1)));
+                state.window.iter().sum::<f64>() / state.window.len() as f64
+            }
+            Smoothing::Ema(_) if first => value,
+            Smoothing::Ema(a) => {
+                let a = a.clamp(0.0, 1.0);
+                state.output * (1.0 - a) + value * a
+            }
+        };
+        state.output
+    }
+
+    /// Forget all previous values.
+    pub fn reset(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = SmootherState::default();
+        }
+    }
+
+    /// How many samples the smoothed value was computed from, out of how many possible.
+    ///
+    /// `None` if it is not known yet.
+    pub fn sample_fill(&self) -> Option<(usize, usize)> {
+        self.state.lock().ok()?.sample_fill
+    }
+
+    /// Record how many samples a value was computed from.
+    ///
+    /// `update` does this automatically with `Smoothing::Window`. Otherwise,
+    /// call this to report the fill of the underlying data (such as a diagnostic's history).
+    pub fn set_sample_fill(&self, samples: usize, max: usize) {
+        if let Ok(mut state) = self.state.lock() {
+            state.sample_fill = Some((samples, max));
         }
     }
-    let secs = secs as u32;
-    format_pretty_time_hms(precision, secs / 3600, secs / 60, secs, value.subsec_nanos())
 }
 
-/// Format time (provided as hours, minutes, seconds, nanoseconds) in a pretty way.
+/// State for displaying the change of a value since the previous update.
 ///
-/// - Right aligned
-/// - `HH:MM:SS.f*` (hours, minutes, seconds, fractional seconds)
-/// - Fractional part optional (precision = 0 to disable)
-/// - Automatically omits hours and minutes if they would be zero
-/// - Padded with spaces to accomodate maximum width
-pub fn format_pretty_time_hms(precision: u8, h: u32, m: u32, s: u32, nanos: u32) -> String {
-    // sanitize
-    let hrs = h % 100;
-    let mins = m % 60;
-    let secs = s % 60;
-    let frac = nanos / 10u32.pow(9 - (precision as u32).min(9));
-    if precision > 0 {
-        if hrs > 0 {
-            format!("{:2}:{:02}:{:02}.{:0w$}", hrs, mins, secs, frac, w = precision as usize)
-        } else if mins > 0 {
-            format!("{:5}:{:02}.{:0w$}", mins, secs, frac, w = precision as usize)
-        } else {
-            format!("{:8}.{:0w$}", secs, frac, w = precision as usize)
+/// Has interior mutability, so it can be updated from `PerfUiEntry::update_value`.
+/// Cloning gives a fresh (empty) `DeltaTracker`.
+#[derive(Debug, Default)]
+pub struct DeltaTracker {
+    state: Mutex<DeltaTrackerState>,
+}
+
+#[derive(Debug, Default)]
+struct DeltaTrackerState {
+    last_time: Option<Instant>,
+    previous: Option<i64>,
+    current: Option<i64>,
+}
+
+impl Clone for DeltaTracker {
+    fn clone(&self) -> Self {
+        DeltaTracker::default()
+    }
+}
+
+impl DeltaTracker {
+    /// Update with a new value, and get the change since the previous value.
+    ///
+    /// `time` is when the value was measured. If it is the same as
+    /// in the previous call, the value is not counted again, so it is fine
+    /// to call this more than once per measurement.
+    ///
+    /// Returns `None` if there is no previous value (on the first update).
+    pub fn update(&self, time: Instant, value: i64) -> Option<i64> {
+        let Ok(mut state) = self.state.lock() else {
+            return None;
+        };
+        if state.last_time != Some(time) {
+            state.last_time = Some(time);
+            state.previous = state.current;
+            state.current = Some(value);
         }
-    } else {
-        if hrs > 0 {
-            format!("{:2}:{:02}:{:02}", hrs, mins, secs)
-        } else if mins > 0 {
-            format!("{:5}:{:02}", mins, secs)
+        Some(state.current? - state.previous?)
+    }
+
+    /// Update with a new value, for values that have no measurement time.
+    ///
+    /// Unlike `update`, every call counts as a new value, so only call this
+    /// once per update of the entry (from `PerfUiEntry::update_value`).
+    pub fn push(&self, value: i64) -> Option<i64> {
+        let Ok(mut state) = self.state.lock() else {
+            return None;
+        };
+        state.last_time = None;
+        state.previous = state.current;
+        state.current = Some(value);
+        Some(state.current? - state.previous?)
+    }
+
+    /// The change as of the last `update`.
+    pub fn delta(&self) -> Option<i64> {
+        let state = self.state.lock().ok()?;
+        Some(state.current? - state.previous?)
+    }
+
+    /// Format an integer value for an entry with a `show_delta` option.
+    ///
+    /// Without `show_delta`, this is just `format_pretty_int`. Otherwise,
+    /// the change is displayed (like "+12"), optionally after the value
+    /// (like "1240 (+12)"). Fits in the width from [`DeltaTracker::width_hint`].
+    pub fn format_value(&self, digits: u8, value: i64, show_delta: bool, with_value: bool) -> String {
+        match (show_delta, with_value) {
+            (false, _) => format_pretty_int(digits, value),
+            (true, false) => format_pretty_delta(digits, self.delta()),
+            (true, true) => format!(
+                "{} ({})",
+                format_pretty_int(digits, value),
+                format_pretty_delta(digits, self.delta()).trim_start(),
+            ),
+        }
+    }
+
+    /// Width hint for a value formatted with `DeltaTracker::format_value`
+    pub fn width_hint(digits: u8, show_delta: bool, with_value: bool) -> usize {
+        if show_delta && with_value {
+            width_hint_pretty_int(digits) * 2 + 3
         } else {
-            format!("{:8}", secs)
+            width_hint_pretty_int(digits)
         }
     }
 }
 
-/// Width hint for a value formatted with `format_pretty_time`/`format_pretty_time_hms`
-pub fn width_hint_pretty_time(precision: u8) -> usize {
-    if precision > 0 {
-        // "HH:MM:SS.f+"
-        9 + precision as usize
-    } else {
-        8
+/// A custom function to format a numeric value, for the `formatter` of entries.
+///
+/// ```rust
+/// PerfUiEntryManual {
+///     formatter: Some(PerfUiFormatter::new(|v| {
+///         if v > 100.0 { format!("{v:.0} 🔥") } else { format!("{v:.0}") }
+///     })),
+///     ..default()
+/// }
+/// ```
+///
+/// The function is called every time the value is displayed (usually every
+/// frame), so keep it cheap. Calling it has the small cost of a dynamic
+/// call, in addition to whatever the function does (such as allocating
+/// the `String`).
+#[derive(Clone)]
+pub struct PerfUiFormatter(Arc<dyn Fn(f64) -> String + Send + Sync>);
+
+impl PerfUiFormatter {
+    /// Create a formatter from a function.
+    pub fn new(f: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+        PerfUiFormatter(Arc::new(f))
+    }
+
+    /// Format a value.
+    pub fn format(&self, value: f64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl std::fmt::Debug for PerfUiFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PerfUiFormatter(..)")
+    }
+}
+
+/// Advance all of Bevy's clocks by `delta`, for testing.
+///
+/// Advances `Time<Real>`, `Time<Virtual>`, `Time<Fixed>`, and the default
+/// `Time`, inserting any that do not exist yet. Useful to test entries that
+/// display time (such as `PerfUiEntryRunningTime`) with known durations,
+/// calling their `update_value` and `format_value` directly:
+///
+/// ```rust
+/// let mut world = World::new();
+/// advance_time(&mut world, Duration::from_secs(83));
+///
+/// let entry = PerfUiEntryRunningTime {
+///     format_hms: true,
+///     precision: 0,
+///     ..default()
+/// };
+/// let mut state = SystemState::<<PerfUiEntryRunningTime as PerfUiEntry>::SystemParam>::new(&mut world);
+/// let value = entry.update_value(&mut state.get_mut(&mut world)).unwrap();
+/// assert_eq!(entry.format_value(&value).trim(), "1:23");
+/// ```
+///
+/// Note that running the app's schedules (such as with `App::update`) will
+/// update the clocks from the real system time again. To control the time
+/// of a running app, insert Bevy's `TimeUpdateStrategy::ManualDuration` instead.
+pub fn advance_time(world: &mut World, delta: Duration) {
+    advance_clock::<bevy::time::Real>(world, delta);
+    advance_clock::<bevy::time::Virtual>(world, delta);
+    advance_clock::<bevy::time::Fixed>(world, delta);
+    advance_clock::<()>(world, delta);
+}
+
+fn advance_clock<T: Default + Send + Sync + 'static>(world: &mut World, delta: Duration) {
+    world.get_resource_or_insert_with(Time::<T>::default).advance_by(delta);
+}
+
+/// A headless app with the Perf UI, for tests.
+#[cfg(test)]
+pub(crate) fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::input::InputPlugin,
+        crate::PerfUiPlugin,
+    ));
+    app
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ema_converges() {
+        let smoother = Smoother::default();
+        let start = Instant::now();
+        let frame = |i: u64| start + Duration::from_millis(16 * i);
+        // starts from the first value
+        assert_eq!(smoother.update(Smoothing::Ema(0.2), frame(0), 0.0), 0.0);
+        // then moves closer to a constant input every frame
+        let mut distance = 100.0;
+        for i in 1..=50 {
+            let new_distance = 100.0 - smoother.update(Smoothing::Ema(0.2), frame(i), 100.0);
+            assert!(new_distance > 0.0 && new_distance < distance, "{new_distance}");
+            distance = new_distance;
+        }
+        assert!(distance < 0.01, "{distance}");
+        // a measurement is only counted once
+        assert_eq!(smoother.update(Smoothing::Ema(0.2), frame(50), 0.0), 100.0 - distance);
+        // a weight of 1 follows the input
+        assert_eq!(smoother.update(Smoothing::Ema(1.0), frame(51), 3.0), 3.0);
+        assert_eq!(smoother.update(Smoothing::Ema(1.0), frame(52), 7.0), 7.0);
+    }
+
+    #[test]
+    fn delta_tracker() {
+        let delta = DeltaTracker::default();
+        let start = Instant::now();
+        // no previous value yet
+        assert_eq!(delta.update(start, 1240), None);
+        assert_eq!(delta.format_value(4, 1240, true, false), "   --");
+        // the same measurement is not counted again
+        assert_eq!(delta.update(start + Duration::from_millis(16), 1252), Some(12));
+        assert_eq!(delta.update(start + Duration::from_millis(16), 1252), Some(12));
+        assert_eq!(delta.format_value(4, 1252, true, false), "  +12");
+        assert_eq!(delta.format_value(4, 1252, true, true), " 1252 (+12)");
+        assert_eq!(delta.format_value(4, 1252, false, true), " 1252");
+        // without a measurement time, every value counts
+        assert_eq!(delta.push(1250), Some(-2));
+        assert_eq!(delta.push(1250), Some(0));
+        for (show_delta, with_value) in [(false, false), (true, false), (true, true)] {
+            let s = delta.format_value(4, 1250, show_delta, with_value);
+            assert!(s.len() <= DeltaTracker::width_hint(4, show_delta, with_value), "{s}");
+        }
     }
 }
@@ -8,9 +8,15 @@ use crate::prelude::*;
 /// Prelude of predefined widget types.
 pub mod prelude {
     pub use super::bar::PerfUiWidgetBar;
+    pub use super::legend::PerfUiWidgetLegend;
+    #[cfg(feature = "entries")]
+    pub use super::graph::PerfUiGraphFrameTime;
 }
 
 pub mod bar;
+pub mod legend;
+#[cfg(feature = "entries")]
+pub mod graph;
 
 #[cfg(feature = "entries")]
 pub(crate) fn predefined_widgets_plugin(app: &mut App) {
@@ -19,10 +25,49 @@ pub(crate) fn predefined_widgets_plugin(app: &mut App) {
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFrameTime>, _>();
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFPSWorst>, _>();
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFrameTimeWorst>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryGpuFrameTime>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFrameBudget>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryDiagnostic>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryScheduleTime>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntrySpan>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryChannel>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryManual>, _>();
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryEntityCount>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryAllocations>, _>();
     #[cfg(feature = "sysinfo")]
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryCpuUsage>, _>();
     #[cfg(feature = "sysinfo")]
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryMemUsage>, _>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryCpuFrequency>, _>();
     app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFixedOverstep>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryFixedSteps>, _>();
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryInputLatency>, _>();
+    #[cfg(feature = "animation")]
+    app.add_perf_ui_widget::<bar::PerfUiWidgetBar<PerfUiEntryActiveAnimations>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFPS>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFrameTime>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFPSWorst>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFrameTimeWorst>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryGpuFrameTime>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFrameBudget>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryDiagnostic>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryScheduleTime>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntrySpan>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryChannel>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryManual>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryEntityCount>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryAllocations>, _>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryCpuUsage>, _>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryMemUsage>, _>();
+    #[cfg(feature = "sysinfo")]
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryCpuFrequency>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFixedOverstep>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryFixedSteps>, _>();
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryInputLatency>, _>();
+    #[cfg(feature = "animation")]
+    app.add_perf_ui_widget::<legend::PerfUiWidgetLegend<PerfUiEntryActiveAnimations>, _>();
+    app.add_perf_ui_widget::<graph::PerfUiGraphFrameTime, _>();
 }
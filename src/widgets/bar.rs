@@ -12,7 +12,7 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::ecs::system::lifetimeless::SQuery;
 
-use crate::entry::{PerfUiEntry, PerfUiEntryDisplayRange};
+use crate::entry::{PerfUiEntry, PerfUiEntryDisplayRange, PerfUiThrottle};
 use crate::ui::widget::{PerfUiWidget, PerfUiWidgetMarker};
 use crate::utils::ColorGradient;
 
@@ -187,14 +187,14 @@ where
             BackgroundColor(self.bar_background),
             BorderColor(self.bar_border_color),
             Node {
-                border: UiRect::all(Val::Px(self.bar_border_px)),
+                border: UiRect::all(root.px(self.bar_border_px)),
                 height: if let Some(h) = self.bar_height_px {
-                    Val::Px(h)
+                    root.px(h)
                 } else {
                     Val::Auto
                 },
                 width: if let Some(w) = self.bar_length_px {
-                    Val::Px(w)
+                    root.px(w)
                 } else {
                     Val::Auto
                 },
@@ -216,9 +216,9 @@ where
             Node {
                 position_type: PositionType::Absolute,
                 top: Val::Px(0.0),
-                bottom: Val::Px(self.bar_border_px * 2.0),
+                bottom: root.px(self.bar_border_px * 2.0),
                 left: Val::Px(0.0),
-                right: Val::Px(self.bar_border_px * 2.0),
+                right: root.px(self.bar_border_px * 2.0),
                 ..default()
             },
         )).id();
@@ -244,9 +244,9 @@ where
         };
         let e_bar_wrapper = commands.spawn((
             Node {
-                padding: UiRect::all(Val::Px(4.0)),
+                padding: UiRect::all(root.px(4.0)),
                 width: if let Some(w) = root.values_col_width {
-                    Val::Px(w)
+                    root.px(w)
                 } else {
                     Val::Auto
                 },
@@ -278,11 +278,11 @@ where
                 Node {
                     margin: match self.text_position {
                         BarTextPosition::OutsideEnd => UiRect {
-                            left: Val::Px(4.0),
+                            left: root.px(4.0),
                             ..UiRect::all(Val::Auto)
                         },
                         BarTextPosition::OutsideStart => UiRect {
-                            right: Val::Px(4.0),
+                            right: root.px(4.0),
                             ..UiRect::all(Val::Auto)
                         },
                         _ => UiRect::all(Val::Auto),
@@ -290,9 +290,10 @@ where
                     ..default()
                 },
                 Text(root.text_err.clone()),
+                TextLayout::new_with_justify(root.value_justify),
                 TextFont {
                     font: root.font_value.clone(),
-                    font_size: root.fontsize_value,
+                    font_size: root.fontsize_value * root.ui_scale,
                     ..default()
                 },
                 TextColor(self.text_color_override .unwrap_or(root.err_color))
@@ -309,28 +310,32 @@ where
         }
         let e_widget = commands.spawn((
             parts,
-            BackgroundColor(root.inner_background_color),
+            BackgroundColor(self.entry.row_background().unwrap_or(root.inner_background_color)),
             Node {
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceBetween,
+                flex_direction: root.row_direction(),
+                justify_content: root.row_justify(),
                 align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(root.inner_margin)),
-                padding: UiRect::all(Val::Px(root.inner_padding)),
+                margin: UiRect::all(root.px(root.inner_margin)),
+                padding: UiRect::all(root.px(root.inner_padding)),
+                column_gap: root.scale_val(root.label_value_gap),
                 ..default()
             },
         )).id();
         if root.display_labels {
             let e_label_wrapper = commands.spawn((
                 Node {
-                    padding: UiRect::all(Val::Px(4.0)),
+                    padding: UiRect::all(root.px(4.0)),
+                    min_width: root.label_min_width(),
+                    overflow: root.label_overflow(),
                     ..default()
                 },
             )).id();
             let e_label = commands.spawn((
-                Text(format!("{}: ", self.entry.label())),
+                Text(root.format_label(self.entry.label())),
+                root.label_text_layout(),
                 TextFont {
                     font: root.font_label.clone(),
-                    font_size: root.fontsize_label,
+                    font_size: root.fontsize_label * root.ui_scale,
                     ..default()
                 },
                 TextColor(root.label_color)
@@ -363,7 +368,8 @@ where
             if entry_highlight {
                 bgcolor.0 = root.inner_background_color_highlight;
             } else {
-                bgcolor.0 = root.inner_background_color;
+                bgcolor.0 = self.entry.row_background()
+                    .unwrap_or(root.inner_background_color);
             }
 
             if let Ok((mut bar_color, mut bar_style)) = q_bar_inner.get_mut(parts.e_bar_inner) {
@@ -410,7 +416,8 @@ where
                         font.font = root.font_value.clone();
                     }
                     if self.text_color_override.is_none() {
-                        let new_color = self.entry.value_color(&value)
+                        let new_color = self.entry.fixed_color()
+                            .or_else(|| self.entry.value_color_with_palette(&value, root.color_good, root.color_bad))
                             .unwrap_or(root.default_value_color);
                         *color = TextColor(new_color);
                     }
@@ -428,5 +435,17 @@ where
     fn sort_key(&self) -> i32 {
         self.entry.sort_key()
     }
+
+    fn entry_label(&self) -> Option<&str> {
+        Some(self.entry.label())
+    }
+
+    fn entry_enabled(&self) -> bool {
+        self.entry.enabled()
+    }
+
+    fn throttle(&self) -> Option<PerfUiThrottle> {
+        self.entry.throttle()
+    }
 }
 
@@ -0,0 +1,471 @@
+//! Frame Time Graph Widget
+//!
+//! Displays the frame time as a graph of the last few seconds, with
+//! a marker line at your target frame time. Very useful for spotting
+//! stutters, which can be hard to notice as a single number.
+//!
+//! To use it, insert a [`PerfUiGraphFrameTime`] component to your Perf
+//! UI entity, instead of a [`PerfUiEntryFrameTime`].
+
+use std::collections::VecDeque;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::ecs::system::lifetimeless::{SQuery, SRes};
+
+use crate::entries::diagnostics::PerfUiEntryFrameTime;
+use crate::entry::PerfUiEntry;
+use crate::ui::widget::{PerfUiWidget, PerfUiWidgetMarker};
+
+/// Display the frame time as a graph over the last few seconds.
+///
+/// The graph is made of vertical bars. Each bar shows the worst
+/// frame time during its slice of time. Bars that exceed the target
+/// frame time are displayed in a different color.
+#[derive(Component)]
+pub struct PerfUiGraphFrameTime {
+    /// How many seconds of history to display.
+    ///
+    /// Default: `5.0`
+    pub seconds: f32,
+    /// The target frame time in milliseconds (your frame budget).
+    ///
+    /// A marker line is displayed at this value.
+    ///
+    /// Default: `1000.0 / 60.0` (60 FPS)
+    pub target_ms: f32,
+    /// The frame time (in milliseconds) at the top of the graph.
+    ///
+    /// Higher values will be clipped.
+    ///
+    /// If `None`, twice the `target_ms` is used.
+    ///
+    /// Default: `None`
+    pub max_ms: Option<f32>,
+    /// Display the graph using a logarithmic scale.
+    ///
+    /// Useful if the frame time varies by orders of magnitude, so that
+    /// short frames are still visible next to very long ones. Values of
+    /// zero or below are clamped to a tiny positive value.
+    ///
+    /// Default: `false`
+    pub log_scale: bool,
+    /// With `log_scale`, how many decades (powers of ten) the graph
+    /// should span, below `max_ms`.
+    ///
+    /// If `None`, the range is computed from the lowest value in the
+    /// history (spanning at least one decade).
+    ///
+    /// Default: `None`
+    pub log_decades: Option<f32>,
+    /// How many bars to split the graph into.
+    ///
+    /// Default: `60`
+    pub bar_count: usize,
+    /// The color of bars within the target frame time.
+    pub bar_color: Color,
+    /// The color of bars that exceed the target frame time.
+    pub bar_color_over_target: Color,
+    /// The color of the marker line at the target frame time.
+    pub target_line_color: Color,
+    /// The background color of the graph.
+    pub graph_background: Color,
+    /// The height of the graph in pixels.
+    ///
+    /// Default: `32.0`
+    pub graph_height_px: f32,
+    /// Force the graph to have a specific width in pixels.
+    ///
+    /// If `None`, the graph will grow to fill the available space.
+    ///
+    /// Default: `Some(128.0)`
+    pub graph_width_px: Option<f32>,
+    /// Also display the current value as text, next to the graph.
+    ///
+    /// Default: `true`
+    pub display_value: bool,
+    /// The entry (data source for the graph widget).
+    ///
+    /// By default, `smoothed` is disabled, so that spikes are visible.
+    pub entry: PerfUiEntryFrameTime,
+}
+
+impl Default for PerfUiGraphFrameTime {
+    fn default() -> Self {
+        PerfUiGraphFrameTime {
+            seconds: 5.0,
+            target_ms: 1000.0 / 60.0,
+            max_ms: None,
+            log_scale: false,
+            log_decades: None,
+            bar_count: 60,
+            bar_color: Color::srgb(0.25, 0.75, 0.25),
+            bar_color_over_target: Color::srgb(1.0, 0.25, 0.25),
+            target_line_color: Color::srgba(1.0, 1.0, 1.0, 0.5),
+            graph_background: Color::srgba(0.0, 0.0, 0.0, 0.5),
+            graph_height_px: 32.0,
+            graph_width_px: Some(128.0),
+            display_value: true,
+            entry: PerfUiEntryFrameTime {
+                smoothed: false,
+                ..default()
+            },
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Component)]
+pub struct PerfUiGraphFrameTimeParts {
+    e_bars: Vec<Entity>,
+    e_target_line: Entity,
+    e_text: Option<Entity>,
+    /// (time in seconds, frame time in ms)
+    history: VecDeque<(f64, f64)>,
+}
+
+#[doc(hidden)]
+#[derive(Component)]
+pub struct GraphWidgetBarMarker;
+
+#[doc(hidden)]
+#[derive(Component)]
+pub struct GraphWidgetTextMarker;
+
+#[doc(hidden)]
+#[derive(Component)]
+pub struct GraphWidgetTargetLineMarker;
+
+/// Values are clamped to this, to take their logarithm.
+const LOG_SCALE_EPSILON: f64 = 1e-6;
+
+type GraphWidgetMarker = PerfUiWidgetMarker<PerfUiGraphFrameTime>;
+
+impl PerfUiGraphFrameTime {
+    fn max_ms(&self) -> f32 {
+        self.max_ms.unwrap_or(self.target_ms * 2.0)
+    }
+
+    /// The frame time at the bottom of the graph, in log scale.
+    ///
+    /// `lowest` is the lowest value in the history, if any.
+    fn log_min_ms(&self, lowest: Option<f64>) -> f64 {
+        let max = (self.max_ms() as f64).max(LOG_SCALE_EPSILON);
+        let decades = match self.log_decades {
+            Some(decades) => decades.max(0.0) as f64,
+            None => {
+                let lowest = lowest.unwrap_or(max).max(LOG_SCALE_EPSILON);
+                (max.log10() - lowest.log10()).ceil().max(1.0)
+            }
+        };
+        max / 10f64.powf(decades)
+    }
+
+    /// Where the value should be on the graph, from 0.0 (bottom) to 1.0 (top).
+    ///
+    /// `min_ms` is the bottom of the graph, if in log scale.
+    fn graph_fraction(&self, value: f64, min_ms: f64) -> f64 {
+        let max_ms = self.max_ms() as f64;
+        let fraction = if self.log_scale {
+            let lo = min_ms.log10();
+            let hi = max_ms.max(LOG_SCALE_EPSILON).log10();
+            if hi > lo {
+                (value.max(LOG_SCALE_EPSILON).log10() - lo) / (hi - lo)
+            } else {
+                1.0
+            }
+        } else {
+            value / max_ms
+        };
+        if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl PerfUiWidget<PerfUiEntryFrameTime> for PerfUiGraphFrameTime {
+    type SystemParamSpawn = ();
+    type SystemParamUpdate = (
+        <PerfUiEntryFrameTime as PerfUiEntry>::SystemParam,
+        SRes<Time<Real>>,
+        SQuery<(
+            &'static mut BackgroundColor,
+            &'static mut PerfUiGraphFrameTimeParts,
+        ), (
+            With<GraphWidgetMarker>,
+            Without<GraphWidgetBarMarker>,
+        )>,
+        SQuery<(
+            &'static mut BackgroundColor,
+            &'static mut Node,
+        ), (
+            With<GraphWidgetBarMarker>,
+            Without<GraphWidgetMarker>,
+        )>,
+        SQuery<(&'static mut Text, &'static mut TextColor, &'static mut TextFont), With<GraphWidgetTextMarker>>,
+        SQuery<&'static mut Node, (
+            With<GraphWidgetTargetLineMarker>,
+            Without<GraphWidgetBarMarker>,
+        )>,
+    );
+
+    fn spawn(
+        &self,
+        root: &crate::prelude::PerfUiRoot,
+        _e_root: Entity,
+        commands: &mut Commands,
+        _: &mut <Self::SystemParamSpawn as SystemParam>::Item<'_, '_>,
+    ) -> Entity {
+        let bar_count = self.bar_count.max(1);
+        let e_graph = commands.spawn((
+            BackgroundColor(self.graph_background),
+            Node {
+                height: root.px(self.graph_height_px),
+                width: if let Some(w) = self.graph_width_px {
+                    root.px(w)
+                } else {
+                    Val::Auto
+                },
+                flex_grow: if self.graph_width_px.is_some() {
+                    0.0
+                } else {
+                    1.0
+                },
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexEnd,
+                ..default()
+            },
+        )).id();
+        let mut e_bars = Vec::with_capacity(bar_count);
+        for _ in 0..bar_count {
+            let e_bar = commands.spawn((
+                GraphWidgetBarMarker,
+                BackgroundColor(self.bar_color),
+                Node {
+                    width: Val::Percent(100.0 / bar_count as f32),
+                    height: Val::Percent(0.0),
+                    ..default()
+                },
+            )).id();
+            commands.entity(e_graph).add_child(e_bar);
+            e_bars.push(e_bar);
+        }
+        let target_pct = self.graph_fraction(self.target_ms as f64, self.log_min_ms(None)) * 100.0;
+        let e_target_line = commands.spawn((
+            GraphWidgetTargetLineMarker,
+            BackgroundColor(self.target_line_color),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                bottom: Val::Percent(target_pct as f32),
+                height: root.px(1.0),
+                ..default()
+            },
+        )).id();
+        commands.entity(e_graph).add_child(e_target_line);
+
+        let e_graph_wrapper = commands.spawn((
+            Node {
+                padding: UiRect::all(root.px(4.0)),
+                width: if let Some(w) = root.values_col_width {
+                    root.px(w)
+                } else {
+                    Val::Auto
+                },
+                flex_grow: if root.values_col_width.is_some() {
+                    0.0
+                } else {
+                    1.0
+                },
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        )).id();
+        commands.entity(e_graph_wrapper).add_child(e_graph);
+
+        let mut e_text = None;
+        if self.display_value {
+            let e = commands.spawn((
+                GraphWidgetTextMarker,
+                Node {
+                    margin: UiRect {
+                        left: root.px(4.0),
+                        ..UiRect::all(Val::Auto)
+                    },
+                    ..default()
+                },
+                Text(root.text_err.clone()),
+                TextLayout::new_with_justify(root.value_justify),
+                TextFont {
+                    font: root.font_value.clone(),
+                    font_size: root.fontsize_value * root.ui_scale,
+                    ..default()
+                },
+                TextColor(root.err_color),
+            )).id();
+            commands.entity(e_graph_wrapper).add_child(e);
+            e_text = Some(e);
+        }
+
+        let e_widget = commands.spawn((
+            PerfUiGraphFrameTimeParts {
+                e_bars,
+                e_target_line,
+                e_text,
+                history: VecDeque::new(),
+            },
+            BackgroundColor(self.entry.row_background().unwrap_or(root.inner_background_color)),
+            Node {
+                flex_direction: root.row_direction(),
+                justify_content: root.row_justify(),
+                align_items: AlignItems::Center,
+                margin: UiRect::all(root.px(root.inner_margin)),
+                padding: UiRect::all(root.px(root.inner_padding)),
+                column_gap: root.scale_val(root.label_value_gap),
+                ..default()
+            },
+        )).id();
+        if root.display_labels {
+            let e_label_wrapper = commands.spawn((
+                Node {
+                    padding: UiRect::all(root.px(4.0)),
+                    min_width: root.label_min_width(),
+                    overflow: root.label_overflow(),
+                    ..default()
+                },
+            )).id();
+            let e_label = commands.spawn((
+                Text(root.format_label(self.entry.label())),
+                root.label_text_layout(),
+                TextFont {
+                    font: root.font_label.clone(),
+                    font_size: root.fontsize_label * root.ui_scale,
+                    ..default()
+                },
+                TextColor(root.label_color)
+            )).id();
+            commands.entity(e_label_wrapper).add_child(e_label);
+            commands.entity(e_widget).add_child(e_label_wrapper);
+        }
+        commands.entity(e_widget).add_child(e_graph_wrapper);
+        e_widget
+    }
+
+    fn update(
+        &self,
+        root: &crate::prelude::PerfUiRoot,
+        _e_root: Entity,
+        e_widget: Entity,
+        (
+            entry_param,
+            time,
+            q_widget,
+            q_bar,
+            q_text,
+            q_target_line,
+        ): &mut <Self::SystemParamUpdate as SystemParam>::Item<'_, '_>,
+    ) {
+        let Ok((mut bgcolor, mut parts)) = q_widget.get_mut(e_widget) else {
+            return;
+        };
+        let parts = &mut *parts;
+
+        let now = time.elapsed_secs_f64();
+        let window = self.seconds.max(0.0) as f64;
+        let value = self.entry.update_value(entry_param);
+        if let Some(value) = value {
+            parts.history.push_back((now, value));
+        }
+        while parts.history.front().is_some_and(|(t, _)| now - *t > window) {
+            parts.history.pop_front();
+        }
+
+        let entry_highlight = value
+            .map(|v| self.entry.value_highlight(&v))
+            .unwrap_or(false);
+        if entry_highlight {
+            bgcolor.0 = root.inner_background_color_highlight;
+        } else {
+            bgcolor.0 = self.entry.row_background()
+                .unwrap_or(root.inner_background_color);
+        }
+
+        // the worst frame time in each bar's slice of time,
+        // from the oldest (leftmost) to the newest (rightmost)
+        let n_bars = parts.e_bars.len();
+        let mut worst = vec![None::<f64>; n_bars];
+        if n_bars > 0 && window > 0.0 {
+            let slice = window / n_bars as f64;
+            for &(t, v) in parts.history.iter() {
+                let age = ((now - t) / slice) as usize;
+                if age < n_bars {
+                    let w = &mut worst[n_bars - 1 - age];
+                    *w = Some(w.map_or(v, |w| w.max(v)));
+                }
+            }
+        }
+
+        let min_ms = if self.log_scale {
+            let lowest = worst.iter().flatten().copied().reduce(f64::min);
+            let min_ms = self.log_min_ms(lowest);
+            // the range can change, so the target line can move
+            if let Ok(mut line_node) = q_target_line.get_mut(parts.e_target_line) {
+                let target_pct = self.graph_fraction(self.target_ms as f64, min_ms) * 100.0;
+                line_node.bottom = Val::Percent(target_pct as f32);
+            }
+            min_ms
+        } else {
+            0.0
+        };
+        for (e_bar, worst) in parts.e_bars.iter().zip(worst) {
+            let Ok((mut bar_color, mut bar_node)) = q_bar.get_mut(*e_bar) else {
+                continue;
+            };
+            let pct = worst
+                .map(|v| self.graph_fraction(v, min_ms) * 100.0)
+                .unwrap_or(0.0);
+            bar_node.height = Val::Percent(pct as f32);
+            bar_color.0 = if worst.is_some_and(|v| v > self.target_ms as f64) {
+                self.bar_color_over_target
+            } else {
+                self.bar_color
+            };
+        }
+
+        if let Some((mut text, mut color, mut font)) = parts.e_text.and_then(|e| q_text.get_mut(e).ok()) {
+            if let Some(value) = value {
+                let s = self.entry.format_value(&value);
+                *text = Text(s.trim().to_owned());
+                if entry_highlight {
+                    font.font = root.font_highlight.clone();
+                } else {
+                    font.font = root.font_value.clone();
+                }
+                let new_color = self.entry.fixed_color()
+                    .or_else(|| self.entry.value_color_with_palette(&value, root.color_good, root.color_bad))
+                    .unwrap_or(root.default_value_color);
+                *color = TextColor(new_color);
+            } else {
+                *text = Text(root.text_err.trim().to_owned());
+                font.font = root.font_value.clone();
+                *color = TextColor(root.err_color);
+            }
+        }
+    }
+
+    fn sort_key(&self) -> i32 {
+        PerfUiEntry::sort_key(&self.entry)
+    }
+
+    fn entry_label(&self) -> Option<&str> {
+        Some(PerfUiEntry::label(&self.entry))
+    }
+
+    fn entry_enabled(&self) -> bool {
+        PerfUiEntry::enabled(&self.entry)
+    }
+}
@@ -0,0 +1,227 @@
+//! Legend Widget
+//!
+//! Displays the color scale of a Perf UI entry: a horizontal strip
+//! showing which color is used for which value, with the min and max
+//! values at either end. Helps decode the colors, especially when
+//! several entries share the same gradient.
+//!
+//! To use it, wrap an entry (configured like the one you want to explain)
+//! in the [`PerfUiWidgetLegend`] struct, and insert that as a component
+//! to your Perf UI entity, alongside your other entries.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::entry::{PerfUiEntry, PerfUiEntryDisplayRange};
+use crate::ui::widget::PerfUiWidget;
+
+/// Display the color scale of a Perf UI entry as a Legend Widget.
+///
+/// This struct wraps the entry type, which will be the source of the
+/// colors (from its `value_color`) and the range of values (from its
+/// `min_value_hint` and `max_value_hint`). The entry's value is never read.
+///
+/// The legend is computed when it is spawned, so it does not cost
+/// anything to update. If the range is not known, the strip is empty.
+#[derive(Component)]
+pub struct PerfUiWidgetLegend<E: PerfUiEntryDisplayRange> {
+    /// How many colored segments to split the strip into.
+    ///
+    /// Default: `16`
+    pub segments: usize,
+    /// Display the min and max values at either end of the strip.
+    ///
+    /// Default: `true`
+    pub display_range: bool,
+    /// The height of the strip in pixels.
+    ///
+    /// Default: `8.0`
+    pub strip_height_px: f32,
+    /// Force the strip to have a specific length in pixels.
+    ///
+    /// Default: `None` (fill the available space)
+    pub strip_length_px: Option<f32>,
+    /// The entry (source of the colors and the range).
+    pub entry: E,
+}
+
+impl<V, E> PerfUiWidgetLegend<E>
+where
+    V: num_traits::Num + num_traits::NumCast + Copy,
+    E: PerfUiEntry<Value = V> + PerfUiEntryDisplayRange,
+{
+    /// Create a new Legend widget with default settings
+    pub fn new(entry: E) -> Self {
+        Self {
+            segments: 16,
+            display_range: true,
+            strip_height_px: 8.0,
+            strip_length_px: None,
+            entry,
+        }
+    }
+
+    fn get_range(&self) -> Option<(f64, f64)> {
+        use num_traits::NumCast;
+        let v_min = self.entry.min_value_hint()
+            .and_then(|v| <f64 as NumCast>::from(v))?;
+        let v_max = self.entry.max_value_hint()
+            .and_then(|v| <f64 as NumCast>::from(v))?;
+        (v_max > v_min).then_some((v_min, v_max))
+    }
+}
+
+impl<V, E> PerfUiWidget<E> for PerfUiWidgetLegend<E>
+where
+    V: num_traits::Num + num_traits::NumCast + Copy,
+    E: PerfUiEntry<Value = V> + PerfUiEntryDisplayRange,
+{
+    type SystemParamSpawn = ();
+    type SystemParamUpdate = ();
+
+    fn spawn(
+        &self,
+        root: &crate::prelude::PerfUiRoot,
+        _e_root: Entity,
+        commands: &mut Commands,
+        _: &mut <Self::SystemParamSpawn as SystemParam>::Item<'_, '_>,
+    ) -> Entity {
+        use num_traits::NumCast;
+        let range = self.get_range();
+
+        let e_strip = commands.spawn((
+            Node {
+                height: root.px(self.strip_height_px),
+                width: if let Some(w) = self.strip_length_px {
+                    root.px(w)
+                } else {
+                    Val::Auto
+                },
+                flex_grow: if self.strip_length_px.is_some() {
+                    0.0
+                } else {
+                    1.0
+                },
+                align_self: AlignSelf::Center,
+                ..default()
+            },
+        )).id();
+        if let Some((v_min, v_max)) = range {
+            let n = self.segments.max(1);
+            for i in 0..n {
+                // the color at the middle of each segment
+                let t = (i as f64 + 0.5) / n as f64;
+                let color = <V as NumCast>::from(v_min + (v_max - v_min) * t)
+                    .and_then(|v| self.entry.value_color_with_palette(&v, root.color_good, root.color_bad))
+                    .unwrap_or(root.default_value_color);
+                let e_segment = commands.spawn((
+                    BackgroundColor(color),
+                    Node {
+                        flex_grow: 1.0,
+                        ..default()
+                    },
+                )).id();
+                commands.entity(e_strip).add_child(e_segment);
+            }
+        }
+
+        let e_strip_wrapper = commands.spawn((
+            Node {
+                padding: UiRect::all(root.px(4.0)),
+                width: if let Some(w) = root.values_col_width {
+                    root.px(w)
+                } else {
+                    Val::Auto
+                },
+                flex_grow: if root.values_col_width.is_some() {
+                    0.0
+                } else {
+                    1.0
+                },
+                column_gap: root.px(4.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        )).id();
+        let spawn_range_text = |commands: &mut Commands, value: Option<f64>| {
+            let s = value
+                .and_then(|v| <V as NumCast>::from(v))
+                .map(|v| self.entry.format_value(&v))
+                .unwrap_or_else(|| root.text_err.clone());
+            commands.spawn((
+                Text(s.trim().to_owned()),
+                TextFont {
+                    font: root.font_value.clone(),
+                    font_size: root.fontsize_label * root.ui_scale,
+                    ..default()
+                },
+                TextColor(root.label_color),
+            )).id()
+        };
+        if self.display_range {
+            let e_min = spawn_range_text(commands, range.map(|r| r.0));
+            commands.entity(e_strip_wrapper).add_child(e_min);
+        }
+        commands.entity(e_strip_wrapper).add_child(e_strip);
+        if self.display_range {
+            let e_max = spawn_range_text(commands, range.map(|r| r.1));
+            commands.entity(e_strip_wrapper).add_child(e_max);
+        }
+
+        let e_widget = commands.spawn((
+            BackgroundColor(self.entry.row_background().unwrap_or(root.inner_background_color)),
+            Node {
+                flex_direction: root.row_direction(),
+                justify_content: root.row_justify(),
+                align_items: AlignItems::Center,
+                margin: UiRect::all(root.px(root.inner_margin)),
+                padding: UiRect::all(root.px(root.inner_padding)),
+                column_gap: root.scale_val(root.label_value_gap),
+                ..default()
+            },
+        )).id();
+        if root.display_labels {
+            let e_label_wrapper = commands.spawn((
+                Node {
+                    padding: UiRect::all(root.px(4.0)),
+                    min_width: root.label_min_width(),
+                    overflow: root.label_overflow(),
+                    ..default()
+                },
+            )).id();
+            let e_label = commands.spawn((
+                Text(root.format_label(self.entry.label())),
+                root.label_text_layout(),
+                TextFont {
+                    font: root.font_label.clone(),
+                    font_size: root.fontsize_label * root.ui_scale,
+                    ..default()
+                },
+                TextColor(root.label_color)
+            )).id();
+            commands.entity(e_label_wrapper).add_child(e_label);
+            commands.entity(e_widget).add_child(e_label_wrapper);
+        }
+        commands.entity(e_widget).add_child(e_strip_wrapper);
+        e_widget
+    }
+
+    fn update(
+        &self,
+        _root: &crate::prelude::PerfUiRoot,
+        _e_root: Entity,
+        _e_widget: Entity,
+        _: &mut <Self::SystemParamUpdate as SystemParam>::Item<'_, '_>,
+    ) {
+        // the legend only depends on the configuration,
+        // so everything is done when it is spawned
+    }
+
+    fn sort_key(&self) -> i32 {
+        self.entry.sort_key()
+    }
+
+    fn entry_enabled(&self) -> bool {
+        self.entry.enabled()
+    }
+}